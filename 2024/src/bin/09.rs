@@ -1,3 +1,5 @@
+#![allow(clippy::manual_is_multiple_of)]
+
 use std::collections::VecDeque;
 
 advent_of_code::solution!(9);
@@ -1,4 +1,5 @@
 #![feature(let_chains)]
+#![allow(stable_features)]
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::slice::Iter;
 
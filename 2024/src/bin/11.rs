@@ -1,3 +1,5 @@
+#![allow(clippy::manual_is_multiple_of)]
+
 use cached::proc_macro::cached;
 
 advent_of_code::solution!(11);
@@ -1,4 +1,5 @@
 #![feature(iter_array_chunks)]
+#![allow(unused_features)]
 use std::collections::{HashMap, VecDeque};
 use std::str::FromStr;
 
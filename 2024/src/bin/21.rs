@@ -1,4 +1,5 @@
 #![feature(iter_chain)]
+#![allow(stable_features)]
 
 use cached::proc_macro::cached;
 use itertools::Itertools;
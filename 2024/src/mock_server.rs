@@ -0,0 +1,56 @@
+/// Runs the mock Advent of Code server, e.g. to try out downloads and submissions without a real session.
+///
+/// ```sh
+/// cargo run --features mock-server --bin mock_server -- --port 8080
+/// AOC_BASE_URL=http://127.0.0.1:8080 ADVENT_OF_CODE_SESSION=mock-session cargo download 1
+/// ```
+use std::{path::PathBuf, process, time::Duration};
+
+use advent_of_code::template::cli::{self, CliError, Matches, MOCK_SERVER};
+use advent_of_code::template::mock_server::{MockServer, DEFAULT_COOLDOWN};
+
+struct Args {
+    fixtures: PathBuf,
+    session: String,
+    port: u16,
+    cooldown: Duration,
+}
+
+fn parse(args: &Matches) -> Result<Args, CliError> {
+    let args = Args {
+        fixtures: args
+            .get("--fixtures")?
+            .unwrap_or_else(|| "tests/fixtures/aoc".into()),
+        session: args
+            .get("--session")?
+            .unwrap_or_else(|| "mock-session".into()),
+        port: args.get("--port")?.unwrap_or(8080),
+        cooldown: args
+            .get("--cooldown")?
+            .map_or(DEFAULT_COOLDOWN, Duration::from_secs),
+    };
+
+    Ok(args)
+}
+
+fn main() {
+    let args = parse(&cli::parse_env(&MOCK_SERVER)).unwrap_or_else(|e| cli::exit_with(&e));
+
+    let addr = format!("127.0.0.1:{}", args.port);
+
+    match MockServer::bind(&addr, &args.fixtures, &args.session, args.cooldown) {
+        Ok(server) => {
+            println!(
+                "🎄 Serving \"{}\" on {}, session cookie \"{}\".",
+                args.fixtures.display(),
+                server.url(),
+                args.session
+            );
+            server.join();
+        }
+        Err(e) => {
+            eprintln!("Failed to start mock server: {e}");
+            process::exit(1);
+        }
+    }
+}
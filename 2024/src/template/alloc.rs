@@ -0,0 +1,39 @@
+/// Global allocator that counts heap allocations, enabled with the `count-allocations` feature.
+/// `cargo time` enables the feature when the `allocations` benchmark column is configured.
+#[cfg(feature = "count-allocations")]
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+#[cfg(feature = "count-allocations")]
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+
+#[cfg(feature = "count-allocations")]
+pub struct CountingAlloc;
+
+#[cfg(feature = "count-allocations")]
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Returns the number of heap allocations so far, or [`None`] if allocations are not counted.
+pub fn allocations() -> Option<u64> {
+    #[cfg(feature = "count-allocations")]
+    return Some(ALLOCATIONS.load(Ordering::Relaxed));
+
+    #[cfg(not(feature = "count-allocations"))]
+    None
+}
@@ -0,0 +1,837 @@
+/// Module that talks to the Advent of Code website to download inputs, read puzzles and submit answers.
+/// Replaces the external `aoc-cli` command-line, so no additional tools need to be installed.
+use std::{
+    cell::Cell,
+    env,
+    fmt::Display,
+    fs, io,
+    path::Path,
+    sync::Once,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::template::puzzle::{Puzzle, Year, YearError, FIRST_YEAR};
+use crate::template::{
+    clock,
+    description::get_puzzle_path,
+    event_length, profile,
+    session::{self, Session},
+    AllDays, Day,
+};
+
+pub static DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variables read by the client.
+static ENV_BASE_URL: &str = "AOC_BASE_URL";
+static ENV_USER_AGENT: &str = "AOC_USER_AGENT";
+static ENV_CONTACT: &str = "AOC_CONTACT";
+static ENV_THROTTLE: &str = "AOC_THROTTLE_MS";
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Minimum time between two requests, to go easy on the website when syncing a whole year.
+pub const DEFAULT_THROTTLE: Duration = Duration::from_secs(1);
+
+#[derive(Debug)]
+pub enum AocClientError {
+    MissingSession,
+    /// Neither a contact nor a User-Agent is configured, so requests could not be attributed to the user.
+    MissingContact,
+    /// The website rejected the session cookie, usually because it expired.
+    ExpiredSession,
+    /// `AOC_YEAR` is missing or not a year with an event.
+    Year(YearError),
+    /// There is no event for the year, or it has not started yet.
+    WrongYear(u16),
+    /// The puzzle of a day unlocks in `wait` seconds.
+    NotUnlocked {
+        day: Day,
+        wait: u64,
+    },
+    /// The server responded with an unexpected status code.
+    Status(u16, String),
+    /// The website could not be reached.
+    Network(String),
+    Parser(String),
+    IO(io::Error),
+    /// Days that could not be downloaded by `download --all`, after the other days were.
+    FailedDays(Vec<Day>),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let profile = profile::current();
+        let login = session::login_command();
+
+        match self {
+            AocClientError::MissingSession => write!(
+                f,
+                "no session cookie found{}. Run `{login}` or set {}.",
+                profile
+                    .as_ref()
+                    .map(|p| format!(" for profile `{p}`"))
+                    .unwrap_or_default(),
+                session::var_name(profile.as_deref()),
+            ),
+            AocClientError::MissingContact => write!(
+                f,
+                "no contact is configured for requests to Advent of Code, which asks automated tools to identify who runs them. Set {ENV_CONTACT} in `.cargo/config.toml` to your email address or the URL of your repository."
+            ),
+            AocClientError::ExpiredSession => write!(
+                f,
+                "the session cookie was not accepted, it has probably expired. Log in to Advent of Code again and run `{login}` with the new cookie."
+            ),
+            AocClientError::Year(e) => write!(f, "{e}"),
+            AocClientError::WrongYear(year) => write!(
+                f,
+                "the Advent of Code event of {year} has not started yet. Pass --year to pick an earlier one."
+            ),
+            AocClientError::NotUnlocked { day, wait } => write!(
+                f,
+                "day {day} is not unlocked yet. Try again in {}.",
+                format_countdown(*wait)
+            ),
+            AocClientError::Status(status, url) => {
+                write!(f, "request to {url} failed with status {status}.")
+            }
+            AocClientError::Network(e) => write!(
+                f,
+                "could not reach Advent of Code: {e}. Check your connection, or AOC_BASE_URL if set."
+            ),
+            AocClientError::Parser(e) => write!(f, "unexpected response: {e}"),
+            AocClientError::IO(e) => write!(f, "{e}"),
+            AocClientError::FailedDays(days) => {
+                let days: Vec<String> = days.iter().map(ToString::to_string).collect();
+                write!(
+                    f,
+                    "failed to download days {}. Run `cargo download --all` again to retry.",
+                    days.join(", ")
+                )
+            }
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, response) => {
+                AocClientError::Status(status, response.get_url().to_string())
+            }
+            ureq::Error::Transport(e) => AocClientError::Network(e.to_string()),
+        }
+    }
+}
+
+/// Warn once per run if the session cookie expires soon, or may have expired already.
+fn warn_if_expiring(session: &Session) {
+    static WARNED: Once = Once::new();
+
+    let warning = match session.age().map(session::expires_in) {
+        Some(Some(left)) if left <= session::EXPIRY_WARNING => format!(
+            "the session cookie expires in about {}.",
+            format_countdown(left.as_secs())
+        ),
+        Some(None) => format!(
+            "the session cookie was stored more than {} ago and has probably expired.",
+            format_countdown(session::LIFETIME.as_secs())
+        ),
+        _ => return,
+    };
+
+    WARNED.call_once(|| {
+        eprintln!(
+            "Warning: {warning} Log in to Advent of Code again and run `{}` with the new cookie.",
+            session::login_command()
+        );
+    });
+}
+
+/// The User-Agent of requests: the configured one, or one that names the contact of the user.
+fn user_agent(configured: Option<&str>, contact: Option<&str>) -> Result<String, AocClientError> {
+    fn non_empty(value: Option<&str>) -> Option<&str> {
+        value.map(str::trim).filter(|value| !value.is_empty())
+    }
+
+    match (non_empty(configured), non_empty(contact)) {
+        (Some(user_agent), _) => Ok(user_agent.into()),
+        (None, Some(contact)) => Ok(format!(
+            "advent_of_code/{} (+{contact})",
+            env!("CARGO_PKG_VERSION")
+        )),
+        (None, None) => Err(AocClientError::MissingContact),
+    }
+}
+
+/// Explain an unexpected status of a request about `day`, made at unix time `now`.
+fn classify_status(
+    status: u16,
+    url: &str,
+    year: u16,
+    day: Option<Day>,
+    now: u64,
+) -> AocClientError {
+    match status {
+        // the website answers requests without a valid session with a bad request.
+        400 | 401 => AocClientError::ExpiredSession,
+        404 if !event_started(year, now) => AocClientError::WrongYear(year),
+        404 => match day.filter(|day| unlocks_at(year, *day) > now) {
+            Some(day) => AocClientError::NotUnlocked {
+                day,
+                wait: unlocks_at(year, day) - now,
+            },
+            None => AocClientError::Status(status, url.to_string()),
+        },
+        _ => AocClientError::Status(status, url.to_string()),
+    }
+}
+
+fn event_started(year: u16, now: u64) -> bool {
+    year >= FIRST_YEAR && unlocks_at(year, Day::new(1).unwrap()) <= now
+}
+
+/// Format a longer wait, e.g. `2d 3h 4m` or `4m 5s`.
+pub fn format_countdown(seconds: u64) -> String {
+    let units = [
+        (seconds / 86400, "d"),
+        (seconds / 3600 % 24, "h"),
+        (seconds / 60 % 60, "m"),
+        (seconds % 60, "s"),
+    ];
+
+    let parts: Vec<String> = units
+        .iter()
+        .skip_while(|(value, _)| *value == 0)
+        .take(3)
+        .map(|(value, unit)| format!("{value}{unit}"))
+        .collect();
+
+    if parts.is_empty() {
+        "0s".into()
+    } else {
+        parts.join(" ")
+    }
+}
+
+/// HTTP client for the Advent of Code website.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    year: Year,
+    throttle: Duration,
+    last_request: Cell<Option<Instant>>,
+}
+
+/// What a download did for a day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Download {
+    /// The puzzle was written, and the input if `input` is set.
+    Fetched { input: bool },
+    /// The input and puzzle were present, nothing was requested.
+    Skipped,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, user_agent: &str, year: Year) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(user_agent)
+            .timeout(TIMEOUT)
+            .build();
+
+        Client {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            year,
+            throttle: DEFAULT_THROTTLE,
+            last_request: Cell::new(None),
+        }
+    }
+
+    /// Set the minimum time between two requests.
+    #[must_use]
+    pub fn with_throttle(mut self, throttle: Duration) -> Self {
+        self.throttle = throttle;
+        self
+    }
+
+    pub fn year(&self) -> Year {
+        self.year
+    }
+
+    /// Create a client from the environment.
+    /// `AOC_BASE_URL`, `AOC_USER_AGENT` and `AOC_THROTTLE_MS` override the defaults, e.g. to test against a local server.
+    /// Without `AOC_USER_AGENT`, the User-Agent contains the contact from `AOC_CONTACT`, which is required.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let year = Year::from_env().map_err(AocClientError::Year)?;
+        let session = session::find().ok_or(AocClientError::MissingSession)?;
+        warn_if_expiring(&session);
+        let base_url = env::var(ENV_BASE_URL).unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        let user_agent = user_agent(
+            env::var(ENV_USER_AGENT).ok().as_deref(),
+            env::var(ENV_CONTACT).ok().as_deref(),
+        )?;
+        let throttle = env::var(ENV_THROTTLE)
+            .ok()
+            .and_then(|ms| ms.parse().ok())
+            .map_or(DEFAULT_THROTTLE, Duration::from_millis);
+        Ok(Self::new(&base_url, &session.token, &user_agent, year).with_throttle(throttle))
+    }
+
+    /// Sleep until the throttle allows the next request.
+    fn wait_turn(&self) {
+        if let Some(last) = self.last_request.get() {
+            let elapsed = last.elapsed();
+            if elapsed < self.throttle {
+                thread::sleep(self.throttle - elapsed);
+            }
+        }
+        self.last_request.set(Some(Instant::now()));
+    }
+
+    fn day_url(&self, day: Day) -> String {
+        Puzzle::new(self.year, day).url(&self.base_url)
+    }
+
+    /// Fail early for years and days that are known to be locked.
+    fn check_unlocked(&self, day: Option<Day>) -> Result<(), AocClientError> {
+        let now = clock::now();
+        let year = self.year.into_inner();
+
+        if !event_started(year, now) {
+            return Err(AocClientError::WrongYear(year));
+        }
+
+        match day.map(|day| Puzzle::new(self.year, day)) {
+            Some(puzzle) if puzzle.unlocks_at() > now => Err(AocClientError::NotUnlocked {
+                day: puzzle.day,
+                wait: puzzle.unlocks_at() - now,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Request a page about `day`, if any. Puzzles that are known to be locked are not requested,
+    /// as the website asks to not request them before they unlock.
+    fn get(&self, url: &str, day: Option<Day>) -> Result<String, AocClientError> {
+        self.check_unlocked(day)?;
+        self.request(url, day)
+    }
+
+    fn request(&self, url: &str, day: Option<Day>) -> Result<String, AocClientError> {
+        self.wait_turn();
+        let response = self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(status, _) => {
+                    classify_status(status, url, self.year.into_inner(), day, clock::now())
+                }
+                e => e.into(),
+            })?;
+        Ok(response.into_string()?)
+    }
+
+    /// Fetch the personal puzzle input of a day.
+    pub fn input(&self, day: Day) -> Result<String, AocClientError> {
+        self.get(&format!("{}/input", self.day_url(day)), Some(day))
+    }
+
+    /// Fetch the description of a day as Markdown. Contains part two once part one is solved.
+    pub fn puzzle(&self, day: Day) -> Result<String, AocClientError> {
+        let html = self.get(&self.day_url(day), Some(day))?;
+        puzzle_to_markdown(&html)
+    }
+
+    /// Fetch the JSON of a private leaderboard of the year.
+    pub fn leaderboard(&self, id: &str) -> Result<String, AocClientError> {
+        self.get(
+            &format!(
+                "{}/{}/leaderboard/private/view/{id}.json",
+                self.base_url, self.year
+            ),
+            None,
+        )
+    }
+
+    /// Fetch the name of the account the session cookie belongs to.
+    /// The events page is requested, as it exists before the event of the configured year starts.
+    pub fn user(&self) -> Result<String, AocClientError> {
+        let html = self.request(&format!("{}/events", self.base_url), None)?;
+        // the website answers with the public page if the cookie is not valid.
+        parse_user(&html).ok_or(AocClientError::ExpiredSession)
+    }
+
+    /// Download the input and puzzle of a day to `data/`.
+    /// Days with a non-empty input and a puzzle on disk are skipped, unless `force` is set.
+    pub fn download(&self, day: Day, force: bool) -> Result<Download, AocClientError> {
+        let input_path = get_input_path(day);
+        let puzzle_path = get_puzzle_path(day);
+
+        let has_input = fs::metadata(&input_path).is_ok_and(|m| m.len() > 0);
+        let has_puzzle = Path::new(&puzzle_path).exists();
+
+        if has_input && has_puzzle && !force {
+            return Ok(Download::Skipped);
+        }
+
+        let input = force || !has_input;
+        if input {
+            write_file(&input_path, &self.input(day)?)?;
+        }
+        write_file(&puzzle_path, &self.puzzle(day)?)?;
+
+        Ok(Download::Fetched { input })
+    }
+
+    /// Submit an answer and classify the response.
+    pub fn submit(&self, day: Day, part: u8, answer: &str) -> Result<Reply, AocClientError> {
+        self.check_unlocked(Some(day))?;
+        self.wait_turn();
+
+        let url = format!("{}/answer", self.day_url(day));
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+
+        let html = match response {
+            Ok(response) => response.into_string()?,
+            // the website refuses submissions without a valid session.
+            Err(ureq::Error::Status(400 | 401, _)) => {
+                return Ok(Reply {
+                    outcome: Outcome::NotLoggedIn,
+                    message: "Not logged in, check your session cookie.".into(),
+                })
+            }
+            Err(ureq::Error::Status(status, _)) => {
+                return Err(classify_status(
+                    status,
+                    &url,
+                    self.year.into_inner(),
+                    Some(day),
+                    clock::now(),
+                ))
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        let message = extract_articles(&html)
+            .first()
+            .map(|article| html_to_text(article))
+            .ok_or_else(|| AocClientError::Parser("response did not contain a message.".into()))?;
+
+        Ok(Reply {
+            outcome: Outcome::parse(&message),
+            message,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Whether a wrong answer was too high or too low, if the website told.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// Classified response to a submission. Wait times are in seconds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    /// A wrong answer locks submissions for `wait` seconds.
+    Incorrect {
+        hint: Option<Hint>,
+        wait: u64,
+    },
+    AlreadySolved,
+    /// The answer was not judged because of an earlier wrong answer.
+    RateLimited {
+        wait: u64,
+    },
+    NotLoggedIn,
+    /// The response could not be classified.
+    Unknown,
+}
+
+impl Outcome {
+    /// Classify the message of a response page.
+    pub fn parse(message: &str) -> Self {
+        if message.contains("That's the right answer") {
+            Outcome::Correct
+        } else if message.contains("That's not the right answer") {
+            let hint = if message.contains("too high") {
+                Some(Hint::TooHigh)
+            } else if message.contains("too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+
+            Outcome::Incorrect {
+                hint,
+                wait: parse_cooldown(message).unwrap_or(60),
+            }
+        } else if message.contains("You gave an answer too recently") {
+            Outcome::RateLimited {
+                wait: parse_wait(message).unwrap_or(60),
+            }
+        } else if message.contains("Did you already complete it") {
+            Outcome::AlreadySolved
+        } else if message.contains("log in") || message.contains("identify yourself") {
+            Outcome::NotLoggedIn
+        } else {
+            Outcome::Unknown
+        }
+    }
+}
+
+/// Response to a submission, with the message shown by the website.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Reply {
+    pub outcome: Outcome,
+    pub message: String,
+}
+
+/// Parse the lockout after a wrong answer, e.g. `Please wait one minute` or `please wait 5 minutes`.
+fn parse_cooldown(message: &str) -> Option<u64> {
+    let lower = message.to_lowercase();
+    let rest = &lower[lower.find("wait ")? + "wait ".len()..];
+    let amount = rest.split_whitespace().next()?;
+    let minutes = match amount {
+        "one" => 1,
+        n => n.parse().ok()?,
+    };
+    Some(minutes * 60)
+}
+
+/// Parse the time left to wait, e.g. `You have 45s left to wait` or `You have 4m 5s left to wait`.
+fn parse_wait(message: &str) -> Option<u64> {
+    let end = message.find(" left to wait")?;
+    let start = message[..end].rfind("You have ")? + "You have ".len();
+
+    message[start..end]
+        .split_whitespace()
+        .map(|part| {
+            if let Some(m) = part.strip_suffix('m') {
+                m.parse::<u64>().ok().map(|m| m * 60)
+            } else {
+                part.strip_suffix('s')?.parse().ok()
+            }
+        })
+        .sum()
+}
+
+/* -------------------------------------------------------------------------- */
+
+pub fn check() -> Result<(), AocClientError> {
+    Client::from_env().map(|_| ())
+}
+
+pub fn read(day: Day) -> Result<(), AocClientError> {
+    let client = Client::from_env()?;
+    let puzzle = client.puzzle(day)?;
+    write_file(&get_puzzle_path(day), &puzzle)?;
+    println!("{puzzle}");
+    Ok(())
+}
+
+pub fn download(day: Day, force: bool) -> Result<Download, AocClientError> {
+    let download = Client::from_env()?.download(day, force)?;
+    let input_path = get_input_path(day);
+    let puzzle_path = get_puzzle_path(day);
+
+    println!("---");
+    match download {
+        Download::Fetched { input } => {
+            if input {
+                println!("🎄 Successfully wrote input to \"{input_path}\".");
+            } else {
+                println!("🎄 Kept existing input \"{input_path}\".");
+            }
+            println!("🎄 Successfully wrote puzzle to \"{puzzle_path}\".");
+        }
+        Download::Skipped => {
+            println!("🎄 Input \"{input_path}\" and puzzle \"{puzzle_path}\" already exist. Pass --force to download them again.");
+        }
+    }
+    Ok(download)
+}
+
+pub fn submit(day: Day, part: u8, result: &str) -> Result<Reply, AocClientError> {
+    Client::from_env()?.submit(day, part, result)
+}
+
+/// Download the puzzle description again, e.g. to reveal part two after solving part one.
+pub fn update_puzzle(day: Day) -> Result<(), AocClientError> {
+    let client = Client::from_env()?;
+    write_file(&get_puzzle_path(day), &client.puzzle(day)?)?;
+    Ok(())
+}
+
+/// Inputs differ per account, so each profile has its own.
+fn get_input_path(day: Day) -> String {
+    profile::data_path(
+        "inputs",
+        &format!("{day}.txt"),
+        profile::current().as_deref(),
+    )
+}
+
+/// Unix time at which a puzzle unlocks: midnight EST (UTC-5) on the day in december.
+pub fn unlocks_at(year: u16, day: Day) -> u64 {
+    let days = days_from_civil(i64::from(year), 12, i64::from(day.into_inner()));
+    u64::try_from(days * 86400 + 5 * 3600).unwrap_or(0)
+}
+
+/// Days of an event that are unlocked at unix time `now`.
+pub fn unlocked_days(year: u16, now: u64) -> impl Iterator<Item = Day> {
+    AllDays::with_length(event_length(year)).filter(move |day| unlocks_at(year, *day) <= now)
+}
+
+/// Days since the unix epoch of a date in the proleptic gregorian calendar.
+pub(crate) fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Date in the proleptic gregorian calendar of a number of days since the unix epoch, as `(year, month, day)`.
+pub(crate) fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    (year, month as u8, day as u8)
+}
+
+fn write_file(path: &str, contents: &str) -> io::Result<()> {
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, contents)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Name in the header of a page, e.g. `<div class="user">Jane Doe <span class="star-count">42*</span></div>`.
+fn parse_user(html: &str) -> Option<String> {
+    let start = html.find("<div class=\"user\">")?;
+    let rest = &html[start..];
+    let content = &rest[rest.find('>')? + 1..];
+    let name = content[..content.find('<').unwrap_or(content.len())].trim();
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+/// Returns the inner HTML of every `<article>` element of a page.
+fn extract_articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(open_end) = rest[start..].find('>') else {
+            break;
+        };
+        let content_start = start + open_end + 1;
+        let Some(length) = rest[content_start..].find("</article>") else {
+            break;
+        };
+        articles.push(&rest[content_start..content_start + length]);
+        rest = &rest[content_start + length..];
+    }
+
+    articles
+}
+
+/// Convert the puzzle page to Markdown, keeping only the descriptions of both parts.
+fn puzzle_to_markdown(html: &str) -> Result<String, AocClientError> {
+    let articles = extract_articles(html);
+
+    if articles.is_empty() {
+        return Err(AocClientError::Parser(
+            "page did not contain a puzzle description.".into(),
+        ));
+    }
+
+    let parts: Vec<String> = articles
+        .iter()
+        .map(|article| html2md::parse_html(article).trim().to_string())
+        .collect();
+
+    Ok(parts.join("\n\n") + "\n")
+}
+
+/// Convert a response message to plain text.
+fn html_to_text(html: &str) -> String {
+    let markdown = html2md::parse_html(html);
+    let text: Vec<&str> = markdown
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect();
+    text.join("\n")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        classify_status, extract_articles, format_countdown, parse_user, puzzle_to_markdown,
+        unlocked_days, unlocks_at, user_agent, AocClientError, Hint, Outcome,
+    };
+    use crate::{day, template::Day};
+
+    #[test]
+    fn requires_a_contact() {
+        assert_eq!(
+            user_agent(None, Some("jane@example.com")).unwrap(),
+            format!(
+                "advent_of_code/{} (+jane@example.com)",
+                env!("CARGO_PKG_VERSION")
+            )
+        );
+        assert_eq!(user_agent(Some("my-tool"), None).unwrap(), "my-tool");
+        assert!(matches!(
+            user_agent(Some(" "), Some("")),
+            Err(AocClientError::MissingContact)
+        ));
+    }
+
+    #[test]
+    fn parses_user_names() {
+        assert_eq!(
+            parse_user(r#"<div class="user">Jane Doe <span class="star-count">42*</span></div>"#),
+            Some("Jane Doe".into())
+        );
+        assert_eq!(
+            parse_user(r#"<div class="user">(anonymous user #1234)</div>"#),
+            Some("(anonymous user #1234)".into())
+        );
+        assert_eq!(parse_user(r#"<a href="/auth/login">[Log In]</a>"#), None);
+    }
+
+    #[test]
+    fn extracts_articles() {
+        let html = r#"<main><article class="day-desc"><h2>--- Day 1: Test ---</h2></article><p>Your puzzle answer was <code>1</code>.</p><article class="day-desc"><h2 id="part2">--- Part Two ---</h2></article></main>"#;
+        let articles = extract_articles(html);
+        assert_eq!(articles.len(), 2);
+        assert_eq!(articles[0], "<h2>--- Day 1: Test ---</h2>");
+    }
+
+    #[test]
+    fn converts_puzzles_to_markdown() {
+        let html = r#"<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2><p>The <em>Chief Historian</em> is missing.</p></article>"#;
+        let markdown = puzzle_to_markdown(html).unwrap();
+        assert!(markdown.contains("Day 1: Historian Hysteria"));
+        assert!(markdown.contains("*Chief Historian*"));
+    }
+
+    #[test]
+    fn computes_unlock_times() {
+        assert_eq!(unlocks_at(2024, day!(1)), 1_733_029_200);
+        assert_eq!(unlocks_at(2015, Day::new(25).unwrap()), 1_451_019_600);
+
+        let now = unlocks_at(2024, day!(3));
+        assert_eq!(unlocked_days(2024, now).count(), 3);
+        assert_eq!(unlocked_days(2024, now - 1).count(), 2);
+        assert_eq!(unlocked_days(2023, now).count(), 25);
+        assert_eq!(unlocked_days(2025, unlocks_at(2026, day!(1))).count(), 12);
+    }
+
+    #[test]
+    fn classifies_failed_requests() {
+        let now = unlocks_at(2024, day!(3));
+        let classify = |status, year, day| classify_status(status, "url", year, day, now);
+
+        assert!(matches!(
+            classify(400, 2024, Some(day!(1))),
+            AocClientError::ExpiredSession
+        ));
+        assert!(matches!(
+            classify(404, 2024, Some(day!(4))),
+            AocClientError::NotUnlocked { wait: 86400, .. }
+        ));
+        assert!(matches!(
+            classify(404, 2025, Some(day!(1))),
+            AocClientError::WrongYear(2025)
+        ));
+        assert!(matches!(
+            classify(404, 2014, None),
+            AocClientError::WrongYear(2014)
+        ));
+        assert!(matches!(
+            classify(404, 2024, Some(day!(2))),
+            AocClientError::Status(404, _)
+        ));
+        assert!(matches!(
+            classify(500, 2024, None),
+            AocClientError::Status(500, _)
+        ));
+    }
+
+    #[test]
+    fn formats_countdowns() {
+        assert_eq!(format_countdown(0), "0s");
+        assert_eq!(format_countdown(245), "4m 5s");
+        assert_eq!(format_countdown(3 * 86400 + 7200 + 60 + 1), "3d 2h 1m");
+    }
+
+    #[test]
+    fn classifies_responses() {
+        assert_eq!(
+            Outcome::parse("That's the right answer!  You are one gold star closer to finding the Chief Historian."),
+            Outcome::Correct
+        );
+        assert_eq!(
+            Outcome::parse("That's not the right answer; your answer is too low.  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again."),
+            Outcome::Incorrect { hint: Some(Hint::TooLow), wait: 60 }
+        );
+        assert_eq!(
+            Outcome::parse("That's not the right answer.  Because you have guessed incorrectly 4 times on this puzzle, please wait 5 minutes before trying again."),
+            Outcome::Incorrect { hint: None, wait: 300 }
+        );
+        assert_eq!(
+            Outcome::parse("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 5s left to wait."),
+            Outcome::RateLimited { wait: 245 }
+        );
+        assert_eq!(
+            Outcome::parse(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            Outcome::AlreadySolved
+        );
+        assert_eq!(
+            Outcome::parse("To play, please identify yourself via one of these services."),
+            Outcome::NotLoggedIn
+        );
+    }
+
+    #[test]
+    fn errors_without_puzzle() {
+        assert!(puzzle_to_markdown("<main>Please log in.</main>").is_err());
+    }
+}
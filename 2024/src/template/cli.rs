@@ -0,0 +1,801 @@
+/// Module that describes the command-line interface: the commands, their arguments and their help.
+/// The description is used to parse arguments, to print help and usage, and to generate shell completions.
+/// Solution binaries are described the same way, so the arguments `solve` passes on are parsed by the same code.
+use std::{collections::HashMap, env, error::Error, fmt::Display, process, str::FromStr};
+
+/// What kind of value an argument takes, used for its placeholder and for completions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Value {
+    Day,
+    Part,
+    Year,
+    Shell,
+    Template,
+    Path,
+    Text(&'static str),
+}
+
+impl Value {
+    pub fn placeholder(self) -> &'static str {
+        match self {
+            Value::Day => "day",
+            Value::Part => "part",
+            Value::Year => "year",
+            Value::Shell => "shell",
+            Value::Template => "name",
+            Value::Path => "path",
+            Value::Text(name) => name,
+        }
+    }
+
+    /// Check a value that is valid regardless of the other arguments, e.g. a part of a puzzle.
+    /// Other values are checked when they are read, e.g. days depend on `--year`.
+    fn check(self, value: &str) -> Result<(), String> {
+        match self {
+            Value::Part if value != "1" && value != "2" => Err("expecting 1 or 2".into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    Flag,
+    Option(Value),
+    Required(Value),
+    Optional(Value),
+}
+
+/// An argument of a command: a flag, an option with a value, or a positional argument.
+#[derive(Clone, Copy, Debug)]
+pub struct Arg {
+    pub name: &'static str,
+    pub kind: Kind,
+    pub help: &'static str,
+}
+
+impl Arg {
+    const fn flag(name: &'static str, help: &'static str) -> Self {
+        Arg {
+            name,
+            kind: Kind::Flag,
+            help,
+        }
+    }
+
+    const fn option(name: &'static str, value: Value, help: &'static str) -> Self {
+        Arg {
+            name,
+            kind: Kind::Option(value),
+            help,
+        }
+    }
+
+    const fn required(name: &'static str, value: Value, help: &'static str) -> Self {
+        Arg {
+            name,
+            kind: Kind::Required(value),
+            help,
+        }
+    }
+
+    const fn optional(name: &'static str, value: Value, help: &'static str) -> Self {
+        Arg {
+            name,
+            kind: Kind::Optional(value),
+            help,
+        }
+    }
+
+    pub fn is_positional(&self) -> bool {
+        matches!(self.kind, Kind::Required(_) | Kind::Optional(_))
+    }
+
+    fn value(&self) -> Option<Value> {
+        match self.kind {
+            Kind::Flag => None,
+            Kind::Option(value) | Kind::Required(value) | Kind::Optional(value) => Some(value),
+        }
+    }
+
+    /// Check a value passed to the argument.
+    fn check(&self, value: &str) -> Result<(), String> {
+        self.value()
+            .map_or(Ok(()), |kind| kind.check(value))
+            .map_err(|e| format!("invalid value `{value}` for `{}`: {e}", self.name))
+    }
+
+    /// How the argument is shown in usage and help, e.g. `--submit <part>` or `[<id>]`.
+    fn label(&self) -> String {
+        match self.kind {
+            Kind::Flag => self.name.to_string(),
+            Kind::Option(value) => format!("{} <{}>", self.name, value.placeholder()),
+            Kind::Required(_) => format!("<{}>", self.name),
+            Kind::Optional(_) => format!("[<{}>]", self.name),
+        }
+    }
+}
+
+/// A command and its arguments. Arguments of a command apply to all of its subcommands.
+#[derive(Debug)]
+pub struct Command {
+    pub name: &'static str,
+    pub about: &'static str,
+    pub args: &'static [Arg],
+    pub subcommands: &'static [Command],
+}
+
+impl Command {
+    fn find_subcommand(&self, name: &str) -> Option<&'static Command> {
+        self.subcommands.iter().find(|command| command.name == name)
+    }
+
+    pub fn positionals(&self) -> impl Iterator<Item = &Arg> {
+        self.args.iter().filter(|arg| arg.is_positional())
+    }
+
+    pub fn options(&self) -> impl Iterator<Item = &Arg> {
+        self.args.iter().filter(|arg| !arg.is_positional())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+const SUBMIT: Arg = Arg::option("--submit", Value::Part, "Submit the answer to a part");
+const YES: Arg = Arg::flag("--yes", "Submit without asking for confirmation");
+const TIME: Arg = Arg::flag("--time", "Benchmark the solution");
+
+/// Arguments of a solution binary. Commands that run solutions pass them on, see [`SolutionArgs`].
+pub static SOLUTION: Command = Command {
+    name: "cargo run --bin <day> --",
+    about: "Run the solution of a day.",
+    args: &[SUBMIT, YES, TIME],
+    subcommands: &[],
+};
+
+/// Arguments of the mock server binary, see `src/mock_server.rs`.
+#[cfg(feature = "mock-server")]
+pub static MOCK_SERVER: Command = Command {
+    name: "cargo run --features mock-server --bin mock_server --",
+    about: "Serve a mock of the Advent of Code website.",
+    args: &[
+        Arg::option(
+            "--fixtures",
+            Value::Path,
+            "Directory of the responses, defaults to tests/fixtures/aoc",
+        ),
+        Arg::option(
+            "--session",
+            Value::Text("cookie"),
+            "Session cookie to accept, defaults to mock-session",
+        ),
+        Arg::option(
+            "--port",
+            Value::Text("port"),
+            "Port to listen on, defaults to 8080",
+        ),
+        Arg::option(
+            "--cooldown",
+            Value::Text("seconds"),
+            "Seconds submissions are locked after a wrong answer",
+        ),
+    ],
+    subcommands: &[],
+};
+
+const DAY: Arg = Arg::required("day", Value::Day, "Day of the puzzle, e.g. 1");
+const WATCH: Arg = Arg::flag("--watch", "Run again whenever the files of the day change");
+const RELEASE: Arg = Arg::flag("--release", "Run in release mode");
+
+/// The commands of the project, each run through its cargo alias, e.g. `cargo solve 1`.
+/// The options of this command apply to all of them.
+pub static APP: Command = Command {
+    name: "cargo run --",
+    about: "Solve Advent of Code puzzles in Rust.",
+    args: &[
+        Arg::option(
+            "--profile",
+            Value::Text("name"),
+            "Use the session cookie and inputs of a profile",
+        ),
+        Arg::option("--year", Value::Year, "Work on the puzzles of another year"),
+    ],
+    subcommands: &[
+        Command {
+            name: "scaffold",
+            about: "Create the solution and example files of a day.",
+            args: &[
+                DAY,
+                Arg::flag("--download", "Download the input and description too"),
+                Arg::flag("--overwrite", "Replace an existing solution file"),
+                Arg::flag("--dry-run", "Show which files would be created, without writing them"),
+                Arg::option(
+                    "--template",
+                    Value::Template,
+                    "Template of the solution, e.g. grid",
+                ),
+            ],
+            subcommands: &[],
+        },
+        Command {
+            name: "download",
+            about: "Download the input and puzzle description of a day.",
+            args: &[
+                Arg::optional("day", Value::Day, "Day of the puzzle, e.g. 1"),
+                Arg::flag("--all", "Download every unlocked day"),
+                Arg::flag("--force", "Download files that exist already"),
+            ],
+            subcommands: &[],
+        },
+        Command {
+            name: "read",
+            about: "Print the puzzle description of a day.",
+            args: &[DAY],
+            subcommands: &[],
+        },
+        Command {
+            name: "examples",
+            about: "List the code blocks of a puzzle description, or write picked ones to example files.",
+            args: &[
+                DAY,
+                Arg::option(
+                    "--pick",
+                    Value::Text("blocks"),
+                    "Blocks to write, e.g. 1,3",
+                ),
+                Arg::flag("--overwrite", "Replace existing example files"),
+            ],
+            subcommands: &[],
+        },
+        Command {
+            name: "solve",
+            about: "Run the solution of a day.",
+            args: &[
+                DAY,
+                RELEASE,
+                Arg::flag("--dhat", "Profile heap allocations with dhat"),
+                SUBMIT,
+                YES,
+                WATCH,
+            ],
+            subcommands: &[],
+        },
+        Command {
+            name: "test-day",
+            about: "Run the tests of a day.",
+            args: &[DAY, WATCH],
+            subcommands: &[],
+        },
+        Command {
+            name: "all",
+            about: "Run the solutions of all days.",
+            args: &[
+                RELEASE,
+                Arg::flag("--all-profiles", "Check the solutions against the inputs of every profile"),
+            ],
+            subcommands: &[],
+        },
+        Command {
+            name: "time",
+            about: "Benchmark solutions and store their timings in the readme.",
+            args: &[
+                Arg::optional("day", Value::Day, "Day of the puzzle, e.g. 1"),
+                Arg::flag("--all", "Benchmark every day, not only those without timings"),
+                Arg::flag("--store", "Store the timings in the readme"),
+                Arg::option(
+                    "--compare",
+                    Value::Text("ref"),
+                    "Compare against the timings of a git revision",
+                ),
+                Arg::option(
+                    "--toolchain",
+                    Value::Text("toolchain"),
+                    "Compare against another toolchain, e.g. nightly",
+                ),
+            ],
+            subcommands: &[],
+        },
+        Command {
+            name: "answer",
+            about: "Record the accepted answer to a part.",
+            args: &[
+                DAY,
+                Arg::required("part", Value::Part, "Part of the puzzle, 1 or 2"),
+                Arg::required("answer", Value::Text("answer"), "The accepted answer"),
+            ],
+            subcommands: &[],
+        },
+        Command {
+            name: "status",
+            about: "Show where each day of the year stands in a calendar.",
+            args: &[Arg::flag("--no-tests", "Do not run the tests of the days")],
+            subcommands: &[],
+        },
+        Command {
+            name: "stars",
+            about: "Update the stars table in the readme.",
+            args: &[],
+            subcommands: &[],
+        },
+        Command {
+            name: "readme",
+            about: "Update the generated sections of the readme.",
+            args: &[],
+            subcommands: &[],
+        },
+        Command {
+            name: "leaderboard",
+            about: "Show a private leaderboard.",
+            args: &[
+                Arg::optional("id", Value::Text("id"), "Id of the leaderboard"),
+                Arg::option("--file", Value::Path, "Read the leaderboard from a JSON file"),
+                Arg::option("--day", Value::Day, "Show the results of a day"),
+            ],
+            subcommands: &[],
+        },
+        Command {
+            name: "auth",
+            about: "Store and inspect the session cookie.",
+            args: &[],
+            subcommands: &[
+                Command {
+                    name: "login",
+                    about: "Store the session cookie.",
+                    args: &[Arg::optional(
+                        "token",
+                        Value::Text("token"),
+                        "The session cookie, read from stdin if omitted",
+                    )],
+                    subcommands: &[],
+                },
+                Command {
+                    name: "status",
+                    about: "Show where the session cookie is stored, the account and when it expires.",
+                    args: &[],
+                    subcommands: &[],
+                },
+            ],
+        },
+        #[cfg(feature = "today")]
+        Command {
+            name: "today",
+            about: "Scaffold, download and read the puzzle of the day.",
+            args: &[Arg::flag("--wait", "Wait for the next puzzle to unlock")],
+            subcommands: &[],
+        },
+        Command {
+            name: "completions",
+            about: "Print a shell completion script.",
+            args: &[Arg::required("shell", Value::Shell, "bash, zsh or fish")],
+            subcommands: &[],
+        },
+    ],
+};
+
+/* -------------------------------------------------------------------------- */
+
+/// Result of parsing the command line.
+#[derive(Debug)]
+pub enum Parsed {
+    Run(Matches),
+    Help(String),
+}
+
+/// Arguments matched against a command, by name.
+#[derive(Debug)]
+pub struct Matches {
+    /// Names of the command and its subcommands, e.g. `auth status`.
+    pub command: String,
+    flags: Vec<&'static str>,
+    values: HashMap<&'static str, String>,
+    usage: Usage,
+}
+
+impl Matches {
+    pub fn flag(&self, name: &str) -> bool {
+        self.flags.contains(&name)
+    }
+
+    /// Parse the value of an argument, if it was passed.
+    pub fn get<T>(&self, name: &str) -> Result<Option<T>, CliError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.values
+            .get(name)
+            .map(|value| {
+                value.parse().map_err(|e| {
+                    self.usage
+                        .error(format!("invalid value `{value}` for `{name}`: {e}"))
+                })
+            })
+            .transpose()
+    }
+
+    /// Parse the value of a required argument.
+    pub fn require<T>(&self, name: &str) -> Result<T, CliError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.get(name)?
+            .ok_or_else(|| self.usage.error(format!("missing argument <{name}>")))
+    }
+}
+
+/// An error in the command line, shown with the usage of the command.
+#[derive(Debug, PartialEq, Eq)]
+pub struct CliError {
+    pub message: String,
+    usage: String,
+    invocation: String,
+}
+
+impl Error for CliError {}
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}\n\nUsage: {}\n\nFor more information, try `{} --help`.",
+            self.message, self.usage, self.invocation
+        )
+    }
+}
+
+/// Usage of a command, kept to report errors in its arguments.
+#[derive(Debug, Clone)]
+struct Usage {
+    usage: String,
+    invocation: String,
+}
+
+impl Usage {
+    fn of(path: &[&Command]) -> Self {
+        Usage {
+            usage: usage(path),
+            invocation: invocation(path),
+        }
+    }
+
+    fn error(&self, message: String) -> CliError {
+        CliError {
+            message,
+            usage: self.usage.clone(),
+            invocation: self.invocation.clone(),
+        }
+    }
+}
+
+/// Parse arguments against a command. Options of a command may be passed after its subcommands.
+pub fn parse(
+    root: &'static Command,
+    args: impl IntoIterator<Item = String>,
+) -> Result<Parsed, CliError> {
+    let mut path = vec![root];
+    let mut flags = vec![];
+    let mut values = HashMap::new();
+    let mut positionals = 0;
+    let mut args = args.into_iter();
+
+    while let Some(token) = args.next() {
+        let command = path[path.len() - 1];
+        let error = |message: String| Usage::of(&path).error(message);
+
+        if token == "-h" || token == "--help" {
+            return Ok(Parsed::Help(help(&path)));
+        }
+
+        if token.starts_with("--") {
+            let (name, inline) = match token.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_string())),
+                None => (token.as_str(), None),
+            };
+
+            let Some(arg) = path
+                .iter()
+                .rev()
+                .flat_map(|command| command.options())
+                .find(|arg| arg.name == name)
+            else {
+                return Err(error(format!("unexpected argument `{name}`")));
+            };
+
+            match (arg.kind, inline) {
+                (Kind::Flag, None) => flags.push(arg.name),
+                (Kind::Flag, Some(_)) => {
+                    return Err(error(format!("`{name}` does not take a value")));
+                }
+                (_, value) => {
+                    let Some(value) = value.or_else(|| args.next()) else {
+                        return Err(error(format!("`{}` expects a value", arg.label())));
+                    };
+                    arg.check(&value).map_err(error)?;
+                    values.insert(arg.name, value);
+                }
+            }
+        } else if token.len() > 1 && token.starts_with('-') {
+            return Err(error(format!("unexpected argument `{token}`")));
+        } else if !command.subcommands.is_empty() {
+            match command.find_subcommand(&token) {
+                Some(subcommand) => path.push(subcommand),
+                None => return Err(error(format!("unknown command `{token}`"))),
+            }
+        } else {
+            let Some(arg) = command.positionals().nth(positionals) else {
+                return Err(error(format!("unexpected argument `{token}`")));
+            };
+            arg.check(&token).map_err(error)?;
+            values.insert(arg.name, token);
+            positionals += 1;
+        }
+    }
+
+    let command = path[path.len() - 1];
+    let usage = Usage::of(&path);
+
+    if !command.subcommands.is_empty() {
+        return Err(usage.error("expected a command".into()));
+    }
+
+    if let Some(arg) = command
+        .positionals()
+        .find(|arg| matches!(arg.kind, Kind::Required(_)) && !values.contains_key(arg.name))
+    {
+        return Err(usage.error(format!("missing argument {}", arg.label())));
+    }
+
+    Ok(Parsed::Run(Matches {
+        command: path[1..]
+            .iter()
+            .map(|command| command.name)
+            .collect::<Vec<_>>()
+            .join(" "),
+        flags,
+        values,
+        usage,
+    }))
+}
+
+/// Parse the arguments of the process, printing help or an error and exiting if they can not be run.
+pub fn parse_env(root: &'static Command) -> Matches {
+    match parse(root, env::args().skip(1)) {
+        Ok(Parsed::Run(matches)) => matches,
+        Ok(Parsed::Help(help)) => {
+            println!("{help}");
+            process::exit(0);
+        }
+        Err(e) => exit_with(&e),
+    }
+}
+
+/// Print an error in the command line and exit.
+pub fn exit_with(error: &CliError) -> ! {
+    eprintln!("Error: {error}");
+    process::exit(1);
+}
+
+/// How a command is invoked: the root through `cargo run`, its subcommands through their cargo alias.
+fn invocation(path: &[&Command]) -> String {
+    match path {
+        [root] => root.name.to_string(),
+        [_, commands @ ..] => {
+            let names: Vec<_> = commands.iter().map(|command| command.name).collect();
+            format!("cargo {}", names.join(" "))
+        }
+        [] => String::new(),
+    }
+}
+
+fn usage(path: &[&Command]) -> String {
+    let command = path[path.len() - 1];
+    let mut usage = invocation(path);
+
+    if !command.subcommands.is_empty() {
+        usage.push_str(" <command>");
+    }
+    for arg in command.positionals() {
+        usage.push(' ');
+        usage.push_str(&arg.label());
+    }
+    if path
+        .iter()
+        .any(|command| command.options().next().is_some())
+    {
+        usage.push_str(" [options]");
+    }
+
+    usage
+}
+
+/// Help of a command: what it does, its usage, and its subcommands and arguments.
+pub fn help(path: &[&Command]) -> String {
+    let command = path[path.len() - 1];
+    let mut help = format!("{}\n\nUsage: {}\n", command.about, usage(path));
+
+    let subcommands: Vec<_> = command
+        .subcommands
+        .iter()
+        .map(|subcommand| (subcommand.name.to_string(), subcommand.about))
+        .collect();
+    let positionals: Vec<_> = command
+        .positionals()
+        .map(|arg| (arg.label(), arg.help))
+        .collect();
+    let mut options: Vec<_> = path
+        .iter()
+        .rev()
+        .flat_map(|command| command.options())
+        .map(|arg| (arg.label(), arg.help))
+        .collect();
+    options.push(("-h, --help".into(), "Print help"));
+
+    for (title, entries) in [
+        ("Commands", subcommands),
+        ("Arguments", positionals),
+        ("Options", options),
+    ] {
+        if entries.is_empty() {
+            continue;
+        }
+        let width = entries
+            .iter()
+            .map(|(label, _)| label.len())
+            .max()
+            .unwrap_or(0);
+        help.push_str(&format!("\n{title}:\n"));
+        for (label, text) in entries {
+            help.push_str(&format!("  {label:width$}  {text}\n"));
+        }
+    }
+
+    help.trim_end().to_string()
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Arguments of a solution binary, shared by the commands that run solutions and the binaries themselves.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SolutionArgs {
+    /// Part to submit the answer of.
+    pub submit: Option<u8>,
+    /// Submit without confirmation.
+    pub yes: bool,
+    /// Benchmark the parts.
+    pub time: bool,
+}
+
+impl SolutionArgs {
+    /// Read the arguments of a solution from matches of [`SOLUTION`], or a command that passes them on.
+    pub fn from_matches(matches: &Matches) -> Result<Self, CliError> {
+        Ok(SolutionArgs {
+            submit: matches.get(SUBMIT.name)?,
+            yes: matches.flag(YES.name),
+            time: matches.flag(TIME.name),
+        })
+    }
+
+    /// Read the arguments of the running solution, exiting with its usage if they are invalid.
+    pub fn from_env() -> Self {
+        Self::from_matches(&parse_env(&SOLUTION)).unwrap_or_else(|e| exit_with(&e))
+    }
+
+    /// Arguments to pass on to a solution binary.
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![];
+        if let Some(part) = self.submit {
+            args.extend([SUBMIT.name.to_string(), part.to_string()]);
+        }
+        if self.yes {
+            args.push(YES.name.into());
+        }
+        if self.time {
+            args.push(TIME.name.into());
+        }
+        args
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse, Matches, Parsed, SolutionArgs, APP, SOLUTION};
+    use crate::template::Day;
+
+    fn run(args: &[&str]) -> Matches {
+        match parse(&APP, args.iter().map(|arg| arg.to_string())) {
+            Ok(Parsed::Run(matches)) => matches,
+            other => panic!("expected matches, got {other:?}"),
+        }
+    }
+
+    fn error(args: &[&str]) -> String {
+        match parse(&APP, args.iter().map(|arg| arg.to_string())) {
+            Err(e) => e.to_string(),
+            other => panic!("expected an error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_commands() {
+        let matches = run(&[
+            "solve",
+            "--submit=2",
+            "1",
+            "--release",
+            "--profile",
+            "alice",
+        ]);
+        assert_eq!(matches.command, "solve");
+        assert_eq!(matches.require::<Day>("day").unwrap(), Day::new(1).unwrap());
+        assert_eq!(matches.get::<u8>("--submit").unwrap(), Some(2));
+        assert_eq!(
+            matches.get::<String>("--profile").unwrap(),
+            Some("alice".into())
+        );
+        assert!(matches.flag("--release"));
+        assert!(!matches.flag("--yes"));
+
+        assert_eq!(run(&["auth", "status"]).command, "auth status");
+        let login = run(&["auth", "login", "abc"]);
+        assert_eq!(login.command, "auth login");
+        assert_eq!(login.get::<String>("token").unwrap(), Some("abc".into()));
+        assert_eq!(run(&["download", "--all"]).get::<Day>("day").unwrap(), None);
+    }
+
+    #[test]
+    fn reports_errors_with_usage() {
+        let unknown = error(&["solve", "1", "--relase"]);
+        assert!(unknown.starts_with("unexpected argument `--relase`"));
+        assert!(unknown.contains("Usage: cargo solve <day> [options]"));
+        assert!(unknown.contains("try `cargo solve --help`"));
+
+        assert!(error(&["solve"]).starts_with("missing argument <day>"));
+        assert!(error(&["solve", "1", "2"]).starts_with("unexpected argument `2`"));
+        assert!(error(&["solve", "1", "--submit"]).starts_with("`--submit <part>` expects a value"));
+        assert!(error(&["sovle"]).starts_with("unknown command `sovle`"));
+        assert!(error(&["auth"]).starts_with("expected a command"));
+        assert!(error(&[]).contains("Usage: cargo run -- <command> [options]"));
+
+        let part = error(&["solve", "1", "--submit", "3"]);
+        assert!(part.starts_with("invalid value `3` for `--submit`: expecting 1 or 2"));
+        assert!(part.contains("Usage: cargo solve <day> [options]"));
+        assert!(error(&["solve", "1", "--submit=0"]).starts_with("invalid value `0`"));
+        assert!(error(&["answer", "1", "3", "42"]).starts_with("invalid value `3` for `part`"));
+
+        let invalid = run(&["read", "26"]).require::<Day>("day").unwrap_err();
+        assert!(invalid.message.starts_with("invalid value `26` for `day`"));
+    }
+
+    #[test]
+    fn prints_help() {
+        let Ok(Parsed::Help(help)) = parse(&APP, ["solve".into(), "--help".into()]) else {
+            panic!("expected help");
+        };
+        assert!(
+            help.starts_with("Run the solution of a day.\n\nUsage: cargo solve <day> [options]")
+        );
+        assert!(help.contains("  --submit <part>   Submit the answer to a part"));
+        assert!(help.contains("  --year <year>     Work on the puzzles of another year"));
+
+        let Ok(Parsed::Help(help)) = parse(&APP, ["-h".into()]) else {
+            panic!("expected help");
+        };
+        assert!(help.contains("\nCommands:\n"));
+        assert!(help.contains("  solve "));
+    }
+
+    #[test]
+    fn shares_solution_args() {
+        let solve = run(&["solve", "1", "--submit", "1", "--yes"]);
+        let args = SolutionArgs::from_matches(&solve).unwrap();
+        assert_eq!(args.to_args(), ["--submit", "1", "--yes"]);
+
+        let Ok(Parsed::Run(matches)) = parse(&SOLUTION, args.to_args()) else {
+            panic!("expected matches");
+        };
+        assert_eq!(SolutionArgs::from_matches(&matches).unwrap(), args);
+    }
+}
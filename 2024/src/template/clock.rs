@@ -0,0 +1,9 @@
+/// Module with the wall clock, e.g. to tell which puzzles are unlocked or how long a lockout lasts.
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Seconds since the unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
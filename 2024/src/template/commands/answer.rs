@@ -0,0 +1,24 @@
+use std::process;
+
+use crate::template::answers::{self, get_answer_path};
+use crate::template::commands::stars;
+use crate::template::{profile, Day};
+
+pub fn handle(day: Day, part: u8, answer: &str) {
+    let profile = profile::current();
+
+    if let Err(e) = answers::store(day, part, answer, profile.as_deref()) {
+        eprintln!("Failed to record answer: {e}");
+        process::exit(1);
+    }
+
+    println!(
+        "Recorded answer for day {day}, part {part} in \"{}\".",
+        get_answer_path(day, part, profile.as_deref())
+    );
+
+    // the readme shows the progress of the default profile.
+    if profile.is_none() {
+        stars::handle();
+    }
+}
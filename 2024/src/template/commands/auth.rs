@@ -0,0 +1,106 @@
+use std::{io, process};
+
+use crate::template::aoc_client::{format_countdown, AocClientError, Client};
+use crate::template::{
+    profile,
+    session::{self, Source},
+};
+
+/// Store the session cookie, read from stdin if not given.
+pub fn login(token: Option<String>) {
+    let token = match token {
+        Some(token) => token,
+        None => prompt().unwrap_or_else(|e| {
+            eprintln!("Failed to read session cookie: {e}");
+            process::exit(1);
+        }),
+    };
+
+    let Some(token) = session::parse(&token) else {
+        eprintln!("No session cookie given. Copy the value of the `session` cookie from your browser after logging in to adventofcode.com.");
+        process::exit(1);
+    };
+
+    let path = match session::store(&token) {
+        Ok(path) => path,
+        Err(e) => {
+            eprintln!("Failed to store session cookie: {e}");
+            process::exit(1);
+        }
+    };
+
+    println!("Stored session cookie in \"{}\".", path.display());
+
+    // the environment and the home directory are read before the config directory.
+    match session::find().map(|session| session.source) {
+        Some(Source::File(found)) if found == path => {}
+        Some(source) => {
+            eprintln!(
+                "Warning: the session cookie in the {source} takes precedence over the stored one."
+            );
+        }
+        None => {}
+    }
+
+    match profile::current() {
+        Some(profile) => println!("Run `cargo auth status --profile {profile}` to check it."),
+        None => println!("Run `cargo auth status` to check it."),
+    }
+}
+
+fn prompt() -> io::Result<String> {
+    println!("Paste the value of the `session` cookie of adventofcode.com:");
+    let mut line = String::new();
+    io::stdin().read_line(&mut line)?;
+    Ok(line)
+}
+
+/// Check the session cookie against the website and report where it is stored and when it expires.
+/// A cookie that expires soon is warned about by the client, as with every request.
+pub fn status() -> Result<(), AocClientError> {
+    let session = session::find().ok_or(AocClientError::MissingSession)?;
+    println!("Session cookie: {}", session.source);
+
+    let user = Client::from_env()?.user()?;
+    println!("Logged in as:   {user}");
+
+    match session.age().map(session::expires_in) {
+        Some(Some(left)) => println!(
+            "Expires:        in about {}",
+            format_countdown(left.as_secs())
+        ),
+        Some(None) => println!(
+            "Expires:        any time now, the cookie was stored more than {} ago.",
+            format_countdown(session::LIFETIME.as_secs())
+        ),
+        None => println!("Expires:        unknown, the cookie is not read from a file."),
+    }
+
+    if let Some(root) = session::repository_root() {
+        if let Source::File(path) = &session.source {
+            if path
+                .canonicalize()
+                .is_ok_and(|path| path.starts_with(&root))
+            {
+                eprintln!(
+                    "Warning: the session cookie is stored inside the repository at \"{}\". Move it out, e.g. with `{}`.",
+                    path.display(),
+                    session::login_command()
+                );
+            }
+        }
+
+        let leaks = session::find_leaks(&root, &session.token)
+            .into_iter()
+            .filter(|path| session.source != Source::File(path.clone()));
+
+        for path in leaks {
+            eprintln!(
+                "Warning: \"{}\" contains the session cookie. Remove it before committing.",
+                path.display()
+            );
+        }
+    }
+
+    Ok(())
+}
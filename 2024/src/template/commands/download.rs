@@ -1,14 +1,60 @@
-use crate::template::{aoc_cli, Day};
-use std::process;
+use crate::template::aoc_client::{self, AocClientError, Client, Download};
+use crate::template::{clock, Day};
+use std::io::{stdout, Write};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+pub fn handle(day: Day, force: bool) -> Result<(), AocClientError> {
+    aoc_client::check()?;
+    aoc_client::download(day, force).map(|_| ())
+}
+
+/// Download every unlocked day of `AOC_YEAR`. Days that are already present are skipped,
+/// so an interrupted sync picks up where it left off when run again.
+pub fn handle_all(force: bool) -> Result<(), AocClientError> {
+    let client = Client::from_env()?;
+
+    let days: Vec<Day> =
+        aoc_client::unlocked_days(client.year().into_inner(), clock::now()).collect();
+    let mut fetched = vec![];
+    let mut skipped = vec![];
+    let mut failed = vec![];
+
+    for (i, day) in days.iter().enumerate() {
+        print!("[{:>2}/{}] Day {day}: ", i + 1, days.len());
+        let _ = stdout().flush();
+
+        match client.download(*day, force) {
+            Ok(Download::Fetched { .. }) => {
+                println!("fetched");
+                fetched.push(*day);
+            }
+            Ok(Download::Skipped) => {
+                println!("skipped");
+                skipped.push(*day);
+            }
+            // every other day would fail the same way.
+            Err(e @ AocClientError::ExpiredSession) => {
+                println!("failed");
+                return Err(e);
+            }
+            Err(e) => {
+                println!("failed: {e}");
+                failed.push(*day);
+            }
+        }
     }
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
-    };
+    println!("---");
+    println!(
+        "🎄 {} fetched, {} skipped, {} failed for {}.",
+        fetched.len(),
+        skipped.len(),
+        failed.len(),
+        client.year()
+    );
+
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(AocClientError::FailedDays(failed))
+    }
 }
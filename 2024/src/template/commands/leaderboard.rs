@@ -0,0 +1,56 @@
+use std::{env, fs, path::Path, process};
+
+use crate::template::aoc_client::Client;
+use crate::template::leaderboard::{get_cache_path, read_cache, Leaderboard};
+use crate::template::Day;
+
+/// Environment variable with the id of the leaderboard, usually the user id of its owner.
+static ENV_LEADERBOARD_ID: &str = "AOC_LEADERBOARD_ID";
+
+pub fn handle(id: Option<String>, file: Option<String>, day: Option<Day>) {
+    let json = match file {
+        Some(file) => fs::read_to_string(&file).map_err(|e| format!("failed to read \"{file}\": {e}")),
+        None => match id.or_else(|| env::var(ENV_LEADERBOARD_ID).ok()) {
+            Some(id) => fetch(&id),
+            None => Err(format!(
+                "no leaderboard id given. Pass it as `cargo leaderboard <id>` or set {ENV_LEADERBOARD_ID}."
+            )),
+        },
+    };
+
+    let leaderboard = match json.and_then(|json| Leaderboard::try_from(json.as_str())) {
+        Ok(leaderboard) => leaderboard,
+        Err(e) => {
+            eprintln!("Failed to load leaderboard: {e}");
+            process::exit(1);
+        }
+    };
+
+    let lines = match day {
+        Some(day) => leaderboard.render_day(day),
+        None => leaderboard.render_overview(),
+    };
+
+    for line in lines {
+        println!("{line}");
+    }
+}
+
+/// Fetch a leaderboard, or read it from the cache if it was fetched recently.
+fn fetch(id: &str) -> Result<String, String> {
+    if let Some(json) = read_cache(id) {
+        return Ok(json);
+    }
+
+    let json = Client::from_env()
+        .and_then(|client| client.leaderboard(id))
+        .map_err(|e| e.to_string())?;
+
+    let path = get_cache_path(id);
+    let dir = Path::new(&path).parent().unwrap_or(Path::new("."));
+    if let Err(e) = fs::create_dir_all(dir).and_then(|()| fs::write(&path, &json)) {
+        eprintln!("Failed to cache leaderboard in \"{path}\": {e}");
+    }
+
+    Ok(json)
+}
@@ -1,6 +1,17 @@
 pub mod all;
+pub mod answer;
+pub mod auth;
+pub mod completions;
 pub mod download;
+pub mod examples;
+pub mod leaderboard;
 pub mod read;
+pub mod readme;
 pub mod scaffold;
 pub mod solve;
+pub mod stars;
+pub mod status;
+pub mod test_day;
 pub mod time;
+#[cfg(feature = "today")]
+pub mod today;
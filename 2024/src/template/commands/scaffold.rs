@@ -1,79 +1,93 @@
-use std::{
-    fs::{File, OpenOptions},
-    io::Write,
-    process,
-};
-
+use crate::template::commands::download;
+use crate::template::description::read_title;
+use crate::template::examples::Examples;
+use crate::template::flavors::{render, Flavor, Placeholders};
+use crate::template::puzzle::{self, Year};
+use crate::template::scaffold::{Action, Plan, ScaffoldError};
 use crate::template::Day;
 
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
-
-fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
-    let mut file = OpenOptions::new();
-    if overwrite {
-        file.create(true);
-    } else {
-        file.create_new(true);
+/// Create the solution, input and example files of a day. Existing input and example files are kept,
+/// an existing solution only if `overwrite` is not set. With `dry_run`, only reports what would change.
+/// With `download`, the input and puzzle are downloaded as well, and the files are undone if that fails.
+pub fn handle(
+    day: Day,
+    overwrite: bool,
+    flavor: Option<&str>,
+    dry_run: bool,
+    download: bool,
+) -> Result<(), ScaffoldError> {
+    // solutions are shared by every year, so they would overwrite the ones of the project year.
+    if let Some(year) = puzzle::other_year() {
+        return Err(ScaffoldError::OtherYear(year));
     }
-    file.truncate(true).write(true).open(path)
-}
-
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
-}
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+    let flavor = Flavor::find(flavor)?;
 
-    let mut file = match safe_create_file(&module_path, overwrite) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
-        }
+    // the title and example answers are known if the puzzle was downloaded before.
+    let placeholders = Placeholders {
+        year: Year::from_env().ok(),
+        day,
+        title: read_title(day),
+        answers: Examples::read(day)
+            .map(|examples| examples.answers)
+            .unwrap_or_default(),
     };
+    let (module, unknown) = render(&flavor.template, &placeholders);
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
-        }
+    if !unknown.is_empty() {
+        eprintln!(
+            "Warning: template `{}` contains unknown placeholders: {}.",
+            flavor.name,
+            unknown.join(", ")
+        );
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
-        }
-    }
+    let data = puzzle::data_dir();
+    let mut plan = Plan::default();
+    plan.add(format!("src/bin/{day}.rs"), module, overwrite);
+    plan.add(format!("{data}/inputs/{day}.txt"), "", false);
+    plan.add(format!("{data}/examples/{day}.txt"), "", false);
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
+    let applied = if dry_run { None } else { Some(plan.apply()?) };
+
+    // how to name a file that is written and one that is kept, and a note on the file.
+    let files = [
+        (
+            "module file",
+            "module file",
+            format!(" from template `{}`", flavor.name),
+        ),
+        ("empty input file", "input file", String::new()),
+        ("empty example file", "example file", String::new()),
+    ];
+    for (step, (written, kept, note)) in plan.steps.iter().zip(files) {
+        let path = step.path.display();
+        match (step.action, dry_run) {
+            (Action::Create, false) => println!("Created {written} \"{path}\"{note}"),
+            (Action::Create, true) => println!("Would create {written} \"{path}\"{note}"),
+            (Action::Overwrite, false) => println!("Overwrote {written} \"{path}\"{note}"),
+            (Action::Overwrite, true) => println!("Would overwrite {written} \"{path}\"{note}"),
+            (Action::Keep, _) if step.path.starts_with("src") => {
+                println!("Kept existing {kept} \"{path}\", pass --overwrite to replace it")
+            }
+            (Action::Keep, _) => println!("Kept existing {kept} \"{path}\""),
         }
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    if dry_run {
+        println!("🎄 Dry run, no files were changed.");
+    } else {
+        println!("🎄 Type `cargo solve {day}` to run your solution.");
+    }
+
+    if let Some(applied) = applied.filter(|_| download) {
+        if let Err(e) = download::handle(day, false) {
+            return Err(ScaffoldError::Download {
+                error: e.to_string(),
+                not_restored: applied.rollback(),
+            });
+        }
+    }
+    Ok(())
 }
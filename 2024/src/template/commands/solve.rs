@@ -1,8 +1,29 @@
-use std::process::{Command, Stdio};
+use std::process::{self, Command, Stdio};
 
+use crate::template::cli::SolutionArgs;
+use crate::template::watch::{day_files, watch};
 use crate::template::Day;
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
+pub fn handle(day: Day, release: bool, dhat: bool, solution_args: &SolutionArgs, watching: bool) {
+    if !watching {
+        run(day, release, dhat, solution_args);
+        return;
+    }
+
+    if solution_args.submit.is_some() {
+        eprintln!("--watch can not be combined with --submit, answers would be submitted on every change.");
+        process::exit(1);
+    }
+
+    watch(
+        &format!("cargo solve {day}"),
+        || day_files(day, true),
+        || run(day, release, dhat, solution_args),
+    );
+}
+
+/// Run the solution of a day, returns whether it ran successfully.
+fn run(day: Day, release: bool, dhat: bool, solution_args: &SolutionArgs) -> bool {
     let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
 
     if dhat {
@@ -17,11 +38,7 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
     }
 
     cmd_args.push("--".to_string());
-
-    if let Some(submit_part) = submit_part {
-        cmd_args.push("--submit".to_string());
-        cmd_args.push(submit_part.to_string());
-    }
+    cmd_args.extend(solution_args.to_args());
 
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
@@ -30,5 +47,5 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         .spawn()
         .unwrap();
 
-    cmd.wait().unwrap();
+    cmd.wait().unwrap().success()
 }
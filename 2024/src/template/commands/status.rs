@@ -0,0 +1,47 @@
+use std::process;
+
+use crate::template::clock;
+use crate::template::puzzle::Year;
+use crate::template::status::{render, run_tests, DayStatus, PartStatus};
+use crate::template::timings::Timings;
+
+pub fn handle(with_tests: bool) {
+    let year = match Year::from_env() {
+        Ok(year) => year,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    let timings = Timings::read_from_file();
+    let now = clock::now();
+    let mut days: Vec<DayStatus> = year
+        .days()
+        .map(|day| DayStatus::read(year, day, &timings, now))
+        .collect();
+
+    if with_tests && days.iter().any(|day| day.scaffolded) {
+        println!("Running the tests of every day...");
+        match run_tests() {
+            Some(results) => {
+                for day in &mut days {
+                    day.tests = results.get(&day.day).copied();
+                }
+            }
+            None => eprintln!("Failed to run the tests, check `cargo test` for errors."),
+        }
+    }
+
+    let stars: usize = days
+        .iter()
+        .flat_map(|day| day.parts)
+        .filter(|part| *part == PartStatus::Solved)
+        .count();
+
+    println!("Advent of Code {year}: {stars} ⭐");
+    println!();
+    for line in render(year, &days) {
+        println!("{line}");
+    }
+}
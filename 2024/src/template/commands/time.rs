@@ -1,10 +1,47 @@
 use std::collections::HashSet;
+use std::process;
 
+use crate::template::badges;
+use crate::template::columns::Column;
+use crate::template::compare::{compare, Baseline};
+use crate::template::config::Config;
+use crate::template::description::read_title;
+use crate::template::readme::{benchmarks::Benchmarks, Engine};
 use crate::template::run_multi::run_multi;
 use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::{all_days, Day};
+
+pub fn handle(
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    compare_rev: Option<String>,
+    compare_toolchain: Option<String>,
+) {
+    if compare_rev.is_some() || compare_toolchain.is_some() {
+        let days_to_run = day.map_or_else(|| all_days().collect(), |day| HashSet::from([day]));
+
+        let baseline = Baseline {
+            rev: compare_rev,
+            toolchain: compare_toolchain,
+        };
+
+        if let Err(e) = compare(&days_to_run, &baseline) {
+            eprintln!("Failed to compare benchmarks: {e}");
+            process::exit(1);
+        }
+
+        return;
+    }
+
+    let config = match Config::read_from_file() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Failed to read config: {e}");
+            process::exit(1);
+        }
+    };
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
     let stored_timings = Timings::read_from_file();
 
     let days_to_run = day.map_or_else(
@@ -21,20 +58,61 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let table = config.benchmarks.clone().unwrap_or_default();
+    let count_allocations = table.has_column(Column::Allocations);
+
+    let timings = run_multi(&days_to_run, true, true, count_allocations).unwrap();
+    let merged_timings = stored_timings.merge(&timings);
+
+    // summarize the days that were just run, including their trend against stored timings.
+    if timings.data.len() > 1 || config.benchmarks.is_some() {
+        let summary = Timings {
+            data: merged_timings
+                .data
+                .iter()
+                .filter(|t| days_to_run.contains(&t.day))
+                .cloned()
+                .collect(),
+        };
+
+        println!();
+        for line in table.to_terminal(&summary, &read_title) {
+            println!("{line}");
+        }
+    }
+
+    if let Some(budgets) = &config.budgets {
+        budgets.print_report(&merged_timings);
+    }
 
     if store {
-        let merged_timings = stored_timings.merge(&timings);
         merged_timings.store_file().unwrap();
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
-            Ok(()) => {
+        let benchmarks = Benchmarks {
+            timings: &merged_timings,
+            table: &table,
+        };
+
+        match Engine::new().register(&benchmarks).update() {
+            Ok(_) => {
                 println!("Stored updated benchmarks.");
             }
-            Err(_) => {
-                eprintln!("Failed to store updated benchmarks.");
+            Err(e) => {
+                eprintln!("Failed to store updated benchmarks: {e}");
             }
         }
+
+        let budget = config.budgets.as_ref().and_then(|budgets| budgets.total);
+        if let Err(e) = badges::store_timings(&merged_timings, budget) {
+            eprintln!("Failed to store runtime badge: {e}");
+        }
+    }
+
+    if let Some(budgets) = &config.budgets {
+        if budgets.fail && budgets.is_exceeded(&merged_timings) {
+            eprintln!("Time budget exceeded.");
+            process::exit(1);
+        }
     }
 }
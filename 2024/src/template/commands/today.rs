@@ -0,0 +1,152 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    io::{stdout, Write},
+    path::Path,
+    thread,
+    time::Duration,
+};
+
+use crate::template::aoc_client::{format_countdown, AocClientError};
+use crate::template::commands::{read, scaffold};
+use crate::template::puzzle::{self, Puzzle, Year};
+use crate::template::run_multi::get_path_for_bin;
+use crate::template::scaffold::ScaffoldError;
+use crate::template::{clock, Day};
+
+#[derive(Debug)]
+pub enum TodayError {
+    /// There is no puzzle today, with the next one and the seconds until it unlocks.
+    NoPuzzle(Puzzle, u64),
+    /// The puzzle belongs to another year than the one the project is configured for.
+    OtherYear(Puzzle, Year),
+    Scaffold(ScaffoldError),
+    Client(AocClientError),
+}
+
+impl Error for TodayError {}
+
+impl Display for TodayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TodayError::NoPuzzle(next, wait) => write!(
+                f,
+                "there is no puzzle today. {next} unlocks in {}, run `cargo today --wait` to wait for it.",
+                format_countdown(*wait)
+            ),
+            TodayError::OtherYear(puzzle, year) => write!(
+                f,
+                "{puzzle} is not part of {year}, the year the project is configured for. Set AOC_YEAR in `.cargo/config.toml` of a project for {}.",
+                puzzle.year
+            ),
+            TodayError::Scaffold(e) => write!(f, "{e}"),
+            TodayError::Client(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<ScaffoldError> for TodayError {
+    fn from(e: ScaffoldError) -> Self {
+        TodayError::Scaffold(e)
+    }
+}
+
+impl From<AocClientError> for TodayError {
+    fn from(e: AocClientError) -> Self {
+        TodayError::Client(e)
+    }
+}
+
+/// Scaffold, download and read the puzzle of the day.
+/// With `wait`, waits for the next puzzle to unlock if there is none today or it was already scaffolded.
+pub fn handle(wait: bool) -> Result<(), TodayError> {
+    let now = clock::now();
+
+    let puzzle = match puzzle::today(now) {
+        Some(puzzle) if !(wait && is_scaffolded(puzzle.day)) => puzzle,
+        _ => {
+            let next = puzzle::next_unlock(now);
+            if !wait {
+                return Err(TodayError::NoPuzzle(next, next.unlocks_at() - now));
+            }
+            check_year(next)?;
+            wait_for(next, clock::now, thread::sleep);
+            next
+        }
+    };
+
+    check_year(puzzle)?;
+    scaffold::handle(puzzle.day, false, None, false, true)?;
+    read::handle(puzzle.day)?;
+    Ok(())
+}
+
+fn is_scaffolded(day: Day) -> bool {
+    Path::new(&get_path_for_bin(day)).exists()
+}
+
+/// The puzzle has to belong to the year the project is configured for, as solutions are shared by every year.
+fn check_year(puzzle: Puzzle) -> Result<(), TodayError> {
+    let year = Year::from_env().map_err(AocClientError::Year)?;
+    if year == puzzle.year {
+        Ok(())
+    } else {
+        Err(TodayError::OtherYear(puzzle, year))
+    }
+}
+
+/// Show a countdown until the puzzle unlocks.
+fn wait_for(puzzle: Puzzle, now: impl Fn() -> u64, sleep: impl Fn(Duration)) {
+    let unlock = puzzle.unlocks_at();
+
+    loop {
+        let now = now();
+        if now >= unlock {
+            break;
+        }
+        print!(
+            "\r⏳ {puzzle} unlocks in {:<16}",
+            format_countdown(unlock - now)
+        );
+        let _ = stdout().flush();
+        sleep(Duration::from_secs(1));
+    }
+
+    println!("\r🎄 {puzzle} is unlocked!{:<16}", "");
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::cell::Cell;
+
+    use super::wait_for;
+    use crate::day;
+    use crate::template::puzzle::{Puzzle, Year};
+
+    #[test]
+    fn counts_down_to_the_unlock() {
+        let puzzle = Puzzle::new(Year::new(2024).unwrap(), day!(1));
+        let clock = Cell::new(puzzle.unlocks_at() - 3);
+        let sleeps = Cell::new(0);
+
+        wait_for(
+            puzzle,
+            || clock.get(),
+            |duration| {
+                clock.set(clock.get() + duration.as_secs());
+                sleeps.set(sleeps.get() + 1);
+            },
+        );
+
+        assert_eq!(sleeps.get(), 3);
+        assert_eq!(clock.get(), puzzle.unlocks_at());
+    }
+
+    #[test]
+    fn does_not_wait_for_unlocked_puzzles() {
+        let puzzle = Puzzle::new(Year::new(2024).unwrap(), day!(1));
+        wait_for(puzzle, || puzzle.unlocks_at() + 60, |_| panic!("slept"));
+    }
+}
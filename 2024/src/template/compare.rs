@@ -0,0 +1,338 @@
+/// Module that compares solution timings between two builds of the project.
+/// The baseline is either another git revision, checked out into a temporary worktree,
+/// or the current tree compiled with a different toolchain.
+use std::{
+    collections::HashSet,
+    env::{self, consts::EXE_SUFFIX},
+    fmt::Display,
+    io,
+    path::{Path, PathBuf},
+    process::{self, Command, Stdio},
+};
+
+use crate::template::{
+    all_days,
+    run_multi::{child_commands, get_path_for_bin},
+    timings::{format_nanos, median},
+    Day, ANSI_BOLD, ANSI_GREEN, ANSI_ITALIC, ANSI_RED, ANSI_RESET,
+};
+
+/// Number of times each build is run per day. Runs of both builds are interleaved.
+const ROUNDS: usize = 3;
+
+#[derive(Debug)]
+pub enum Error {
+    Git(String),
+    Build(String),
+    IO(io::Error),
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Git(msg) => write!(f, "git: {msg}"),
+            Error::Build(label) => write!(f, "failed to build {label}."),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// What the current tree is compared against.
+pub struct Baseline {
+    /// A git revision to check out, e.g. `HEAD~1` or `main`.
+    pub rev: Option<String>,
+    /// A rustup toolchain to build the baseline with, e.g. `nightly-2024-12-01`.
+    pub toolchain: Option<String>,
+}
+
+impl Baseline {
+    fn label(&self) -> String {
+        match (&self.rev, &self.toolchain) {
+            (Some(rev), Some(toolchain)) => format!("{rev} (+{toolchain})"),
+            (Some(rev), None) => rev.clone(),
+            (None, Some(toolchain)) => format!("+{toolchain}"),
+            (None, None) => "baseline".into(),
+        }
+    }
+}
+
+/// A buildable copy of the project.
+struct Build {
+    label: String,
+    /// Directory containing the `Cargo.toml` of the build.
+    manifest_dir: PathBuf,
+    target_dir: PathBuf,
+    toolchain: Option<String>,
+}
+
+impl Build {
+    fn has_day(&self, day: Day) -> bool {
+        self.manifest_dir.join(get_path_for_bin(day)).exists()
+    }
+
+    fn compile(&self, days: &[Day]) -> Result<(), Error> {
+        let mut cmd = Command::new("cargo");
+
+        if let Some(toolchain) = &self.toolchain {
+            cmd.arg(format!("+{toolchain}"));
+        }
+
+        cmd.args(["build", "--quiet", "--release", "--target-dir"])
+            .arg(&self.target_dir)
+            .current_dir(&self.manifest_dir);
+
+        for day in days {
+            cmd.args(["--bin", &day.to_string()]);
+        }
+
+        let status = cmd
+            .stdout(Stdio::inherit())
+            .stderr(Stdio::inherit())
+            .status()?;
+
+        if status.success() {
+            Ok(())
+        } else {
+            Err(Error::Build(self.label.clone()))
+        }
+    }
+
+    /// Run the solution for a day and return the timing of each part in nanoseconds.
+    /// Solutions are always run from the current directory, so both builds read the same inputs.
+    fn run(&self, day: Day) -> Result<[Option<f64>; 2], Error> {
+        let bin = self
+            .target_dir
+            .join("release")
+            .join(format!("{day}{EXE_SUFFIX}"));
+
+        let output = Command::new(bin)
+            .arg("--time")
+            .stderr(Stdio::inherit())
+            .output()?;
+
+        let lines: Vec<String> = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(String::from)
+            .collect();
+
+        let timing = child_commands::parse_exec_time(&lines, day);
+
+        Ok([timing.part_1, timing.part_2]
+            .map(|part| part.as_deref().and_then(child_commands::parse_duration)))
+    }
+}
+
+/// A temporary git worktree that is removed when dropped.
+struct Worktree {
+    path: PathBuf,
+}
+
+impl Worktree {
+    fn add(rev: &str) -> Result<Self, Error> {
+        let sanitized: String = rev
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+            .collect();
+
+        let path = env::temp_dir().join(format!("aoc-compare-{sanitized}-{}", process::id()));
+
+        git(
+            &["worktree", "add", "--detach", "--quiet"],
+            Some(&path),
+            Some(rev),
+        )?;
+
+        Ok(Worktree { path })
+    }
+}
+
+impl Drop for Worktree {
+    fn drop(&mut self) {
+        let _ = git(&["worktree", "remove", "--force"], Some(&self.path), None);
+    }
+}
+
+fn git(args: &[&str], path: Option<&Path>, rev: Option<&str>) -> Result<String, Error> {
+    let mut cmd = Command::new("git");
+    cmd.args(args);
+
+    if let Some(path) = path {
+        cmd.arg(path);
+    }
+
+    if let Some(rev) = rev {
+        cmd.arg(rev);
+    }
+
+    let output = cmd
+        .output()
+        .map_err(|_| Error::Git("git could not be called.".into()))?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        Err(Error::Git(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ))
+    }
+}
+
+fn format_speedup(baseline: f64, current: f64) -> String {
+    let ratio = baseline / current;
+
+    if ratio >= 1.0 {
+        format!("{ANSI_GREEN}{ratio:.2}x faster{ANSI_RESET}")
+    } else {
+        format!("{ANSI_RED}{:.2}x slower{ANSI_RESET}", 1.0 / ratio)
+    }
+}
+
+fn print_comparison(part: u8, baseline: &mut [f64], current: &mut [f64]) {
+    match (median(baseline), median(current)) {
+        (Some(b), Some(c)) => println!(
+            "Part {part}: {} → {ANSI_BOLD}{}{ANSI_RESET} ({})",
+            format_nanos(b),
+            format_nanos(c),
+            format_speedup(b, c)
+        ),
+        (None, Some(c)) => println!(
+            "Part {part}: - → {ANSI_BOLD}{}{ANSI_RESET} ({ANSI_ITALIC}new{ANSI_RESET})",
+            format_nanos(c)
+        ),
+        (Some(b), None) => println!("Part {part}: {} → ✖", format_nanos(b)),
+        (None, None) => println!("Part {part}: ✖"),
+    }
+}
+
+/// Build the baseline and the current tree, then run the selected days on both builds.
+pub fn compare(days_to_run: &HashSet<Day>, baseline: &Baseline) -> Result<(), Error> {
+    let cwd = env::current_dir()?;
+
+    // keep the worktree alive until all runs are done.
+    let worktree = match &baseline.rev {
+        Some(rev) => Some(Worktree::add(rev)?),
+        None => None,
+    };
+
+    let baseline_build = match &worktree {
+        Some(worktree) => {
+            // the project may live in a sub-directory of the repository (e.g. `./2024`).
+            let prefix = git(&["rev-parse", "--show-prefix"], None, None)?;
+            let manifest_dir = worktree.path.join(prefix);
+            Build {
+                label: baseline.label(),
+                target_dir: manifest_dir.join("target"),
+                manifest_dir,
+                toolchain: baseline.toolchain.clone(),
+            }
+        }
+        None => Build {
+            label: baseline.label(),
+            manifest_dir: cwd.clone(),
+            target_dir: cwd.join("target").join("compare"),
+            toolchain: baseline.toolchain.clone(),
+        },
+    };
+
+    let current_build = Build {
+        label: "current tree".into(),
+        manifest_dir: cwd.clone(),
+        target_dir: cwd.join("target"),
+        toolchain: None,
+    };
+
+    let days: Vec<Day> = all_days()
+        .filter(|day| days_to_run.contains(day) && current_build.has_day(*day))
+        .collect();
+
+    let baseline_days: Vec<Day> = days
+        .iter()
+        .copied()
+        .filter(|day| baseline_build.has_day(*day))
+        .collect();
+
+    println!(
+        "Building {ANSI_BOLD}{}{ANSI_RESET}...",
+        baseline_build.label
+    );
+    baseline_build.compile(&baseline_days)?;
+    println!("Building {ANSI_BOLD}{}{ANSI_RESET}...", current_build.label);
+    current_build.compile(&days)?;
+
+    for day in days {
+        println!();
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+
+        let mut baseline_samples: [Vec<f64>; 2] = [vec![], vec![]];
+        let mut current_samples: [Vec<f64>; 2] = [vec![], vec![]];
+
+        for round in 0..ROUNDS {
+            // alternate which build goes first to even out warm-up and thermal effects.
+            let mut order = [
+                (&baseline_build, &mut baseline_samples),
+                (&current_build, &mut current_samples),
+            ];
+
+            if round % 2 == 1 {
+                order.reverse();
+            }
+
+            for (build, samples) in order {
+                if !build.has_day(day) {
+                    continue;
+                }
+
+                print!("\r{ANSI_ITALIC}round {}/{ROUNDS}{ANSI_RESET}", round + 1);
+                let _ = io::Write::flush(&mut io::stdout());
+
+                for (part, nanos) in build.run(day)?.into_iter().enumerate() {
+                    if let Some(nanos) = nanos {
+                        samples[part].push(nanos);
+                    }
+                }
+            }
+        }
+
+        print!("\r");
+        for (i, (baseline, current)) in baseline_samples
+            .iter_mut()
+            .zip(current_samples.iter_mut())
+            .enumerate()
+        {
+            #[allow(clippy::cast_possible_truncation)]
+            print_comparison(i as u8 + 1, baseline, current);
+        }
+    }
+
+    // make sure the temporary worktree is removed before the caller exits the process.
+    drop(worktree);
+
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Baseline;
+
+    #[test]
+    fn labels_baselines() {
+        let baseline = Baseline {
+            rev: Some("main".into()),
+            toolchain: Some("nightly-2024-12-01".into()),
+        };
+        assert_eq!(baseline.label(), "main (+nightly-2024-12-01)");
+
+        let baseline = Baseline {
+            rev: None,
+            toolchain: Some("nightly".into()),
+        };
+        assert_eq!(baseline.label(), "+nightly");
+    }
+}
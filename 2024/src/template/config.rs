@@ -0,0 +1,147 @@
+/// Project configuration, read from an optional `aoc.json` next to `Cargo.toml`.
+use std::{collections::HashMap, fs, io::ErrorKind, str::FromStr, sync::OnceLock};
+use tinyjson::JsonValue;
+
+use crate::template::budgets::Budgets;
+use crate::template::columns::BenchmarkTable;
+use crate::template::puzzle::FIRST_YEAR;
+use crate::template::MAX_DAY;
+
+static CONFIG_FILE_PATH: &str = "./aoc.json";
+
+/// Represents the project configuration.
+/// Every section is optional, a missing file yields the default configuration.
+#[derive(Clone, Debug, Default)]
+pub struct Config {
+    pub budgets: Option<Budgets>,
+    pub benchmarks: Option<BenchmarkTable>,
+}
+
+impl Config {
+    /// Read the project configuration. If not present, returns the default configuration.
+    pub fn read_from_file() -> Result<Self, String> {
+        match fs::read_to_string(CONFIG_FILE_PATH) {
+            Ok(contents) => {
+                Config::try_from(contents).map_err(|e| format!("{CONFIG_FILE_PATH}: {e}"))
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(format!("{CONFIG_FILE_PATH}: {e}")),
+        }
+    }
+}
+
+/// Number of days of events set in the `events` section of the configuration, read once.
+/// The section is read on its own, as other sections need the length of the event to parse days.
+/// An invalid configuration sets no lengths, it is reported by the commands that read it.
+pub fn event_lengths() -> &'static HashMap<u16, u8> {
+    static EVENT_LENGTHS: OnceLock<HashMap<u16, u8>> = OnceLock::new();
+
+    EVENT_LENGTHS.get_or_init(|| {
+        let Ok(contents) = fs::read_to_string(CONFIG_FILE_PATH) else {
+            return HashMap::new();
+        };
+        JsonValue::from_str(&contents)
+            .ok()
+            .and_then(|json| {
+                let events = json.get::<HashMap<String, JsonValue>>()?.get("events")?;
+                parse_events(events).ok()
+            })
+            .unwrap_or_default()
+    })
+}
+
+/// Parse the `events` section, e.g. `{ "2026": 15 }`.
+fn parse_events(value: &JsonValue) -> Result<HashMap<u16, u8>, String> {
+    value
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or("Expected events to be a JSON object.")?
+        .iter()
+        .map(|(key, length)| {
+            let year = key
+                .parse::<u16>()
+                .ok()
+                .filter(|year| *year >= FIRST_YEAR)
+                .ok_or_else(|| format!("Expected events.{key} to be a year."))?;
+            let length = length
+                .get::<f64>()
+                .filter(|length| {
+                    length.fract() == 0.0 && (1.0..=f64::from(MAX_DAY)).contains(*length)
+                })
+                .ok_or_else(|| {
+                    format!("Expected events.{key} to be a number of days from 1 to {MAX_DAY}.")
+                })?;
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            Ok((year, *length as u8))
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl TryFrom<String> for Config {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let budgets = json.get("budgets").map(Budgets::try_from).transpose()?;
+
+        let benchmarks = json
+            .get("benchmarks")
+            .map(BenchmarkTable::try_from)
+            .transpose()?;
+
+        // read by `event_lengths`, checked here so that mistakes are reported.
+        json.get("events").map(parse_events).transpose()?;
+
+        Ok(Config {
+            budgets,
+            benchmarks,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_events, Config};
+
+    #[test]
+    fn handles_empty_config() {
+        let config = Config::try_from("{}".to_string()).unwrap();
+        assert!(config.budgets.is_none());
+    }
+
+    #[test]
+    fn handles_budgets() {
+        let json = r#"{ "budgets": { "total": "1s" } }"#.to_string();
+        let config = Config::try_from(json).unwrap();
+        assert!(config.budgets.is_some());
+    }
+
+    #[test]
+    fn handles_event_lengths() {
+        let events = r#"{ "2026": 15 }"#.parse().unwrap();
+        assert_eq!(parse_events(&events).unwrap().get(&2026), Some(&15));
+
+        for events in [
+            r#"{ "2026": 30 }"#,
+            r#"{ "2026": 1.5 }"#,
+            r#"{ "next": 12 }"#,
+        ] {
+            let json = format!(r#"{{ "events": {events} }}"#);
+            assert!(Config::try_from(json).is_err(), "{events}");
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_invalid_json() {
+        Config::try_from("[]".to_string()).unwrap();
+    }
+}
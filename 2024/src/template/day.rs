@@ -2,13 +2,43 @@ use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
 
+use crate::template::config;
+use crate::template::puzzle::Year;
 #[cfg(feature = "today")]
-use chrono::{Datelike, FixedOffset, Utc};
+use crate::template::{clock, puzzle};
+
+/// Number of days of the longest events.
+pub const MAX_DAY: u8 = 25;
+
+/// Number of days of the events, each applying from its year on, unless `aoc.json` sets the length of an event.
+const EVENT_LENGTHS: [(u16, u8); 2] = [(2015, 25), (2025, 12)];
+
+/// Number of days of the event of a year: as set in the `events` section of `aoc.json`,
+/// otherwise 25 until 2024 and 12 from 2025 on.
+#[must_use]
+pub fn event_length(year: u16) -> u8 {
+    config::event_lengths()
+        .get(&year)
+        .copied()
+        .unwrap_or_else(|| default_event_length(year))
+}
 
-#[cfg(feature = "today")]
-const SERVER_UTC_OFFSET: i32 = -5;
+/// Number of days of the event of a year, according to [`EVENT_LENGTHS`].
+fn default_event_length(year: u16) -> u8 {
+    EVENT_LENGTHS
+        .iter()
+        .rev()
+        .find(|(from, _)| year >= *from)
+        .map_or(MAX_DAY, |(_, length)| *length)
+}
+
+/// Number of days of the event of the active year, or of the longest events if no year is set.
+pub fn active_length() -> u8 {
+    Year::from_env().map_or(MAX_DAY, Year::length)
+}
 
 /// A valid day number of advent (i.e. an integer in range 1 to 25).
+/// Events from 2025 on are shorter, days are checked against the event when parsed.
 ///
 /// # Display
 /// This value displays as a two digit number.
@@ -25,7 +55,7 @@ impl Day {
     /// Creates a [`Day`] from the provided value if it's in the valid range,
     /// returns [`None`] otherwise.
     pub fn new(day: u8) -> Option<Self> {
-        if day == 0 || day > 25 {
+        if day == 0 || day > MAX_DAY {
             return None;
         }
         Some(Self(day))
@@ -45,15 +75,9 @@ impl Day {
 
 #[cfg(feature = "today")]
 impl Day {
-    /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
+    /// Returns the current day if it's a day of this year's event in december (UTC-5), `None` otherwise.
     pub fn today() -> Option<Self> {
-        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
-        let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 25 {
-            Self::new(u8::try_from(today.day()).ok()?)
-        } else {
-            None
-        }
+        puzzle::today(clock::now()).map(|puzzle| puzzle.day)
     }
 }
 
@@ -80,40 +104,56 @@ impl PartialOrd<u8> for Day {
 impl FromStr for Day {
     type Err = DayFromStrError;
 
+    /// Parses a day of the event of the active year.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let day = s.parse().map_err(|_| DayFromStrError)?;
-        Self::new(day).ok_or(DayFromStrError)
+        let length = active_length();
+        let error = DayFromStrError { length };
+        let day = s.parse().map_err(|_| error)?;
+        Self::new(day).filter(|day| day.0 <= length).ok_or(error)
     }
 }
 
 /// An error which can be returned when parsing a [`Day`].
-#[derive(Debug)]
-pub struct DayFromStrError;
+#[derive(Debug, Clone, Copy)]
+pub struct DayFromStrError {
+    /// Number of days of the event the day was checked against.
+    pub length: u8,
+}
 
 impl Error for DayFromStrError {}
 
 impl Display for DayFromStrError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("expecting a day number between 1 and 25")
+        write!(f, "expecting a day number between 1 and {}", self.length)
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of the event of the active year.
 pub fn all_days() -> AllDays {
     AllDays::new()
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of an event, from the 1st to its last day.
 pub struct AllDays {
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
+    /// Days of the event of the active year.
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        Self { current: 1 }
+        Self::with_length(active_length())
+    }
+
+    /// Days of an event with `length` days, at most 25.
+    pub fn with_length(length: u8) -> Self {
+        Self {
+            current: 1,
+            last: length.min(MAX_DAY),
+        }
     }
 }
 
@@ -121,7 +161,7 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.last {
             return None;
         }
         // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
@@ -135,15 +175,18 @@ impl Iterator for AllDays {
 /* -------------------------------------------------------------------------- */
 
 /// Creates a [`Day`] value in a const context.
+/// Only checks that the day is between 1 and 25: the length of the active event, which may be set
+/// in `aoc.json`, is not known at compile time. E.g. `day!(20)` compiles for a 12 day event,
+/// days are checked against the active event when parsed instead.
 #[macro_export]
 macro_rules! day {
     ($day:expr) => {{
         const _ASSERT: () = assert!(
-            $day != 0 && $day <= 25,
+            $day != 0 && $day <= $crate::template::MAX_DAY,
             concat!(
                 "invalid day number `",
                 $day,
-                "`, expecting a value between 1 and 25"
+                "`, expecting a number between 1 and 25"
             ),
         );
         $crate::template::Day::__new_unchecked($day)
@@ -154,11 +197,11 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use super::{default_event_length, event_length, AllDays, Day};
 
     #[test]
     fn all_days_iterator() {
-        let mut iter = all_days();
+        let mut iter = AllDays::with_length(25);
 
         assert_eq!(iter.next(), Some(Day(1)));
         assert_eq!(iter.next(), Some(Day(2)));
@@ -187,6 +230,23 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn event_lengths() {
+        assert_eq!(event_length(2015), 25);
+        assert_eq!(event_length(2024), 25);
+        assert_eq!(event_length(2025), 12);
+        assert_eq!(AllDays::with_length(12).last(), Some(Day(12)));
+        assert_eq!(AllDays::with_length(30).count(), 25);
+    }
+
+    #[test]
+    fn default_event_lengths() {
+        assert_eq!(default_event_length(2014), 25);
+        assert_eq!(default_event_length(2024), 25);
+        assert_eq!(default_event_length(2025), 12);
+        assert_eq!(default_event_length(2030), 12);
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -0,0 +1,52 @@
+/// Helpers for the puzzle descriptions that are downloaded to `data/puzzles`.
+use std::fs;
+
+use crate::template::{puzzle, Day};
+
+#[must_use]
+pub fn get_puzzle_path(day: Day) -> String {
+    format!("{}/puzzles/{day}.md", puzzle::data_dir())
+}
+
+/// Read the title of a downloaded puzzle, e.g. `Historian Hysteria`.
+pub fn read_title(day: Day) -> Option<String> {
+    parse_title(&fs::read_to_string(get_puzzle_path(day)).ok()?)
+}
+
+/// Parse the title from a puzzle heading such as `--- Day 1: Historian Hysteria ---`.
+/// Headings may be escaped (`\---`) or prefixed with `#`, depending on the Markdown converter.
+fn parse_title(markdown: &str) -> Option<String> {
+    markdown.lines().find_map(|line| {
+        let heading = line
+            .trim()
+            .trim_start_matches(['#', '\\', ' '])
+            .strip_prefix("--- Day ")?
+            .strip_suffix("---")?;
+        let (_, title) = heading.split_once(':')?;
+        Some(title.trim().to_string())
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::parse_title;
+
+    #[test]
+    fn parses_escaped_titles() {
+        let md = "\\--- Day 1: Historian Hysteria ---\n----------\n\nThe *Chief Historian*...";
+        assert_eq!(parse_title(md), Some("Historian Hysteria".into()));
+    }
+
+    #[test]
+    fn parses_heading_titles() {
+        let md = "## --- Day 12: Garden Groups ---\n\nWhy not search for the Chief Historian...";
+        assert_eq!(parse_title(md), Some("Garden Groups".into()));
+    }
+
+    #[test]
+    fn handles_missing_titles() {
+        assert_eq!(parse_title("no heading here"), None);
+    }
+}
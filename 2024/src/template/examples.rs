@@ -0,0 +1,255 @@
+/// Module that extracts example inputs and their expected answers from a downloaded puzzle description.
+/// Example blocks are the fenced code blocks of the Markdown, expected answers the emphasized code spans.
+use std::fs;
+
+use crate::template::{description::get_puzzle_path, puzzle, Day};
+
+static PART_TWO_HEADING: &str = "--- Part Two ---";
+static TEST_PLACEHOLDER: &str = "assert_eq!(result, None);";
+static EXAMPLE_READ: &str = "read_file(\"examples\", DAY)";
+
+/// A code block of the puzzle description.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Block {
+    /// Part of the puzzle the block appears in.
+    pub part: u8,
+    pub content: String,
+}
+
+/// Example blocks and proposed answers of a puzzle.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Examples {
+    pub blocks: Vec<Block>,
+    /// Last emphasized code span of each part, which usually is the answer for the example.
+    pub answers: [Option<String>; 2],
+}
+
+impl Examples {
+    /// Parse the examples of a downloaded puzzle, if present.
+    pub fn read(day: Day) -> Option<Self> {
+        Some(Self::parse(&fs::read_to_string(get_puzzle_path(day)).ok()?))
+    }
+
+    pub fn parse(markdown: &str) -> Self {
+        let mut examples = Examples::default();
+        let mut part = 1;
+        let mut block: Option<Vec<&str>> = None;
+
+        for line in markdown.lines() {
+            if line.trim_start().starts_with("```") {
+                match block.take() {
+                    Some(lines) => examples.blocks.push(Block {
+                        part,
+                        content: lines.join("\n").trim_end().to_string(),
+                    }),
+                    None => block = Some(vec![]),
+                }
+                continue;
+            }
+
+            if let Some(lines) = block.as_mut() {
+                lines.push(line);
+                continue;
+            }
+
+            if line.contains(PART_TWO_HEADING) {
+                part = 2;
+            }
+
+            if let Some(answer) = emphasized_code(line).pop() {
+                examples.answers[usize::from(part) - 1] = Some(answer);
+            }
+        }
+
+        examples
+    }
+}
+
+/// Inline code that is emphasized, written as `` `*11*` `` or `` *`11`* `` by the Markdown converter.
+fn emphasized_code(line: &str) -> Vec<String> {
+    let segments: Vec<&str> = line.split('`').collect();
+
+    // code spans are at the odd indices, an unclosed span at the end is ignored.
+    (1..segments.len().saturating_sub(1))
+        .step_by(2)
+        .filter_map(|i| {
+            let code = segments[i];
+            if code.len() > 2 && code.starts_with('*') && code.ends_with('*') {
+                Some(code[1..code.len() - 1].to_string())
+            } else if segments[i - 1].ends_with('*') && segments[i + 1].starts_with('*') {
+                Some(code.to_string())
+            } else {
+                None
+            }
+        })
+        .filter(|code| !code.is_empty())
+        .collect()
+}
+
+/// Paths of the example files for `count` examples: `01.txt` for one, `01-1.txt`, `01-2.txt`... for more.
+#[must_use]
+pub fn get_example_paths(day: Day, count: usize) -> Vec<String> {
+    let data = puzzle::data_dir();
+    if count == 1 {
+        vec![format!("{data}/examples/{day}.txt")]
+    } else {
+        (1..=count)
+            .map(|i| format!("{data}/examples/{day}-{i}.txt"))
+            .collect()
+    }
+}
+
+/// Fill the expected answers into the tests of a freshly scaffolded solution.
+/// With `per_part`, tests that read `DD.txt` are switched to `DD-1.txt` and `DD-2.txt`,
+/// even if their answers were already filled in when the solution was scaffolded.
+/// Returns `None` if the tests were already edited or nothing could be changed.
+pub fn fill_tests(original: &str, answers: &[Option<String>; 2], per_part: bool) -> Option<String> {
+    let mut source = original.to_string();
+
+    // back to front, so replacing part two does not shift the placeholder of part one.
+    if source.matches(TEST_PLACEHOLDER).count() == 2 {
+        for (i, answer) in answers.iter().enumerate().rev() {
+            if let Some(answer) = answer.as_ref().filter(|a| a.parse::<u64>().is_ok()) {
+                source = replace_nth(
+                    &source,
+                    TEST_PLACEHOLDER,
+                    &format!("assert_eq!(result, Some({answer}));"),
+                    i,
+                );
+            }
+        }
+    }
+
+    if per_part && source.matches(EXAMPLE_READ).count() == 2 {
+        source = source.replacen(EXAMPLE_READ, "read_file_part(\"examples\", DAY, 1)", 1);
+        source = source.replacen(EXAMPLE_READ, "read_file_part(\"examples\", DAY, 2)", 1);
+    }
+
+    (source != original).then_some(source)
+}
+
+fn replace_nth(source: &str, from: &str, to: &str, n: usize) -> String {
+    match source.match_indices(from).nth(n) {
+        Some((index, _)) => format!("{}{to}{}", &source[..index], &source[index + from.len()..]),
+        None => source.to_string(),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{emphasized_code, fill_tests, get_example_paths, Block, Examples};
+    use crate::{
+        day,
+        template::{
+            flavors::{render, Placeholders},
+            Day,
+        },
+    };
+
+    const PUZZLE: &str = "\\--- Day 1: Historian Hysteria ---
+----------
+
+For example:
+
+```
+3   4
+4   3
+
+```
+
+The distance between them is `*2*`. In total, `2 + 1`, a total distance of `*11*`!
+
+\\--- Part Two ---
+----------
+
+```
+9   9
+```
+
+The similarity score is `*31*` (`9 + 4`).
+";
+
+    #[test]
+    fn parses_blocks_and_answers() {
+        let examples = Examples::parse(PUZZLE);
+        assert_eq!(
+            examples.blocks,
+            vec![
+                Block {
+                    part: 1,
+                    content: "3   4\n4   3".into()
+                },
+                Block {
+                    part: 2,
+                    content: "9   9".into()
+                }
+            ]
+        );
+        assert_eq!(examples.answers, [Some("11".into()), Some("31".into())]);
+    }
+
+    #[test]
+    fn finds_emphasized_code() {
+        assert_eq!(emphasized_code("is `*11*`, not `5`"), vec!["11"]);
+        assert_eq!(emphasized_code("is *`ab`* and `*5*`"), vec!["ab", "5"]);
+        assert!(emphasized_code("an *emphasis* and `code`").is_empty());
+        assert!(emphasized_code("unclosed `*11*").is_empty());
+    }
+
+    #[test]
+    fn names_example_files() {
+        assert_eq!(get_example_paths(day!(1), 1), vec!["data/examples/01.txt"]);
+        assert_eq!(
+            get_example_paths(Day::new(17).unwrap(), 2),
+            vec!["data/examples/17-1.txt", "data/examples/17-2.txt"]
+        );
+    }
+
+    /// A solution scaffolded from the default template, with the given example answers.
+    fn scaffold(answers: [Option<String>; 2]) -> String {
+        let template = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+        let placeholders = Placeholders {
+            year: None,
+            day: day!(1),
+            title: None,
+            answers,
+        };
+        render(template, &placeholders).0
+    }
+
+    #[test]
+    fn fills_scaffolded_tests() {
+        let source = fill_tests(
+            &scaffold([None, None]),
+            &[Some("11".into()), Some("31".into())],
+            true,
+        )
+        .unwrap();
+        assert!(source.contains("assert_eq!(result, Some(11));"));
+        assert!(source.contains("assert_eq!(result, Some(31));"));
+        assert!(source.contains("read_file_part(\"examples\", DAY, 2)"));
+        assert!(source.find("Some(11)") < source.find("Some(31)"));
+
+        let source =
+            fill_tests(&scaffold([None, None]), &[None, Some("31".into())], false).unwrap();
+        assert!(source.contains("assert_eq!(result, None);"));
+        assert!(source.contains("assert_eq!(result, Some(31));"));
+        assert!(source.find("None);") < source.find("Some(31)"));
+
+        assert_eq!(fill_tests(&source, &[Some("11".into()), None], false), None);
+    }
+
+    #[test]
+    fn switches_filled_tests_to_parts() {
+        let answers = [Some("11".to_string()), Some("31".to_string())];
+        let scaffolded = scaffold(answers.clone());
+        assert!(scaffolded.contains("assert_eq!(result, Some(11));"));
+
+        let source = fill_tests(&scaffolded, &answers, true).unwrap();
+        assert!(source.contains("read_file_part(\"examples\", DAY, 1)"));
+        assert!(source.contains("read_file_part(\"examples\", DAY, 2)"));
+        assert_eq!(fill_tests(&scaffolded, &answers, false), None);
+    }
+}
@@ -0,0 +1,321 @@
+/// Module that reads a private leaderboard and renders it for the terminal.
+/// The JSON is the one served at `/<year>/leaderboard/private/view/<id>.json`.
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    str::FromStr,
+    time::{Duration, SystemTime},
+};
+use tinyjson::JsonValue;
+
+use crate::template::aoc_client::unlocks_at;
+use crate::template::{event_length, puzzle, AllDays, Day};
+
+/// The website asks to not request a leaderboard more often than every 15 minutes.
+pub const CACHE_DURATION: Duration = Duration::from_secs(15 * 60);
+
+#[must_use]
+pub fn get_cache_path(id: &str) -> String {
+    format!("{}/leaderboards/{id}.json", puzzle::data_dir())
+}
+
+/// Read a cached leaderboard if it is more recent than [`CACHE_DURATION`].
+pub fn read_cache(id: &str) -> Option<String> {
+    let path = get_cache_path(id);
+    let modified = fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+    let age = SystemTime::now().duration_since(modified).ok()?;
+    (age < CACHE_DURATION)
+        .then(|| fs::read_to_string(&path).ok())
+        .flatten()
+}
+
+/// A member of a private leaderboard.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    /// Anonymous users have no name.
+    pub name: Option<String>,
+    pub stars: u64,
+    pub local_score: u64,
+    /// Unix time each part of a day was solved at.
+    pub completions: BTreeMap<Day, [Option<u64>; 2]>,
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    /// Seconds from the unlock of a day to the completion of each of its parts.
+    pub fn elapsed(&self, year: u16, day: Day) -> [Option<u64>; 2] {
+        let unlock = unlocks_at(year, day);
+        self.completions.get(&day).map_or([None, None], |parts| {
+            parts.map(|ts| ts.map(|ts| ts.saturating_sub(unlock)))
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub year: u16,
+    /// Members, ranked by local score.
+    pub members: Vec<Member>,
+}
+
+impl Leaderboard {
+    fn days(&self) -> AllDays {
+        AllDays::with_length(event_length(self.year))
+    }
+
+    /// Render the standings, with a column per day: `*` for both stars, `.` for the first only.
+    pub fn render_overview(&self) -> Vec<String> {
+        let days: String = self
+            .days()
+            .map(|day| if day.into_inner() % 5 == 1 { '|' } else { ' ' })
+            .collect();
+
+        let header = format!("{:>4} {:>5} {:>5}  {days}", "", "Score", "Stars");
+        let mut lines = vec![header.trim_end().to_string()];
+
+        for (rank, member) in self.members.iter().enumerate() {
+            let stars: String = self
+                .days()
+                .map(|day| match member.completions.get(&day) {
+                    Some([Some(_), Some(_)]) => '*',
+                    Some([Some(_), None]) => '.',
+                    _ => ' ',
+                })
+                .collect();
+
+            lines.push(format!(
+                "{:>3}) {:>5} {:>5}  {stars}  {}",
+                rank + 1,
+                member.local_score,
+                member.stars,
+                member.display_name()
+            ));
+        }
+
+        lines
+    }
+
+    /// Render the completion times of a day relative to its unlock, and the time taken for part two.
+    pub fn render_day(&self, day: Day) -> Vec<String> {
+        let mut solved: Vec<(&Member, [Option<u64>; 2])> = self
+            .members
+            .iter()
+            .map(|member| (member, member.elapsed(self.year, day)))
+            .filter(|(_, elapsed)| elapsed[0].is_some())
+            .collect();
+
+        // fastest to solve both parts first, then fastest part one.
+        solved.sort_by_key(|(_, [one, two])| (two.is_none(), two.or(*one)));
+
+        let mut lines = vec![format!(
+            "{:>4} {:>10} {:>10} {:>10}  Day {day}",
+            "", "Part 1", "Part 2", "Delta"
+        )];
+
+        for (rank, (member, [one, two])) in solved.iter().enumerate() {
+            let delta = one.zip(*two).map(|(one, two)| two.saturating_sub(one));
+            lines.push(format!(
+                "{:>3}) {:>10} {:>10} {:>10}  {}",
+                rank + 1,
+                format_elapsed(*one),
+                format_elapsed(*two),
+                format_elapsed(delta),
+                member.display_name()
+            ));
+        }
+
+        if solved.is_empty() {
+            lines.push(format!("Nobody solved day {day} yet."));
+        }
+
+        lines
+    }
+}
+
+/// Format seconds as `H:MM:SS`, hours may exceed a day.
+pub fn format_elapsed(seconds: Option<u64>) -> String {
+    match seconds {
+        Some(s) => format!("{}:{:02}:{:02}", s / 3600, s / 60 % 60, s % 60),
+        None => "-".into(),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl TryFrom<&str> for Leaderboard {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let year = json
+            .get("event")
+            .and_then(|v| v.get::<String>())
+            .and_then(|event| event.parse().ok())
+            .ok_or("expected leaderboard to have a year as key `event`.")?;
+
+        let mut members = json
+            .get("members")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+            .ok_or("expected leaderboard to have an object as key `members`.")?
+            .values()
+            .map(Member::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // days are checked against the event of the leaderboard, not the one of the project.
+        let length = event_length(year);
+        if let Some(day) = members
+            .iter()
+            .flat_map(|member| member.completions.keys())
+            .find(|day| day.into_inner() > length)
+        {
+            return Err(format!(
+                "expected days of the {year} event, which has {length} days, found day {day}."
+            ));
+        }
+
+        members.sort_by(|a, b| {
+            b.local_score
+                .cmp(&a.local_score)
+                .then(b.stars.cmp(&a.stars))
+                .then(a.id.cmp(&b.id))
+        });
+
+        Ok(Leaderboard { year, members })
+    }
+}
+
+impl TryFrom<&JsonValue> for Member {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected member to be a JSON object.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .map(|v| *v as u64)
+                .ok_or(format!("Expected member to have number key `{key}`."))
+        };
+
+        let name = json.get("name").and_then(|v| v.get::<String>()).cloned();
+
+        let completions = json
+            .get("completion_day_level")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+            .ok_or("Expected member to have object key `completion_day_level`.")?
+            .iter()
+            .map(|(day, parts)| {
+                let day = day
+                    .parse()
+                    .ok()
+                    .and_then(Day::new)
+                    .ok_or(format!("Expected completion of a day, found `{day}`."))?;
+                let parts = parts
+                    .get::<HashMap<String, JsonValue>>()
+                    .ok_or("Expected completion of a day to be a JSON object.")?;
+
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let timestamp = |part: &str| {
+                    parts
+                        .get(part)
+                        .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+                        .and_then(|v| v.get("get_star_ts"))
+                        .and_then(|v| v.get::<f64>())
+                        .map(|ts| *ts as u64)
+                };
+
+                Ok((day, [timestamp("1"), timestamp("2")]))
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(Member {
+            id: number("id")?,
+            name,
+            stars: number("stars")?,
+            local_score: number("local_score")?,
+            completions,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_elapsed, Leaderboard};
+    use crate::day;
+
+    const FIXTURE: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/aoc/leaderboard.json"
+    ));
+
+    #[test]
+    fn parses_leaderboards() {
+        let leaderboard = Leaderboard::try_from(FIXTURE).unwrap();
+        assert_eq!(leaderboard.year, 2024);
+        assert_eq!(leaderboard.members.len(), 2);
+        assert_eq!(leaderboard.members[0].display_name(), "Mock Owner");
+        assert_eq!(leaderboard.members[1].display_name(), "(anonymous user #2)");
+        assert_eq!(
+            leaderboard.members[0].elapsed(2024, day!(1)),
+            [Some(180), Some(1320)]
+        );
+    }
+
+    #[test]
+    fn renders_overview() {
+        let lines = Leaderboard::try_from(FIXTURE).unwrap().render_overview();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].starts_with("  1)     4     2  *"));
+        assert!(lines[2].starts_with("  2)     1     1  ."));
+    }
+
+    #[test]
+    fn renders_days() {
+        let leaderboard = Leaderboard::try_from(FIXTURE).unwrap();
+
+        let lines = leaderboard.render_day(day!(1));
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].contains("0:03:00    0:22:00    0:19:00  Mock Owner"));
+        assert!(lines[2].contains("-          -  (anonymous user #2)"));
+
+        let lines = leaderboard.render_day(day!(2));
+        assert_eq!(lines[1], "Nobody solved day 02 yet.");
+    }
+
+    #[test]
+    fn rejects_invalid_leaderboards() {
+        assert!(Leaderboard::try_from("{}").is_err());
+        assert!(Leaderboard::try_from(r#"{"event": "2024", "members": {"1": {}}}"#).is_err());
+
+        // 2025 has 12 days, even if the project is configured for a longer event.
+        let member =
+            r#"{"id": 1, "stars": 1, "local_score": 1, "completion_day_level": {"13": {}}}"#;
+        let leaderboard = format!(r#"{{"event": "2025", "members": {{"1": {member}}}}}"#);
+        assert!(Leaderboard::try_from(leaderboard.as_str()).is_err());
+        assert!(Leaderboard::try_from(leaderboard.replace("2025", "2024").as_str()).is_ok());
+    }
+
+    #[test]
+    fn formats_elapsed_time() {
+        assert_eq!(format_elapsed(Some(59)), "0:00:59");
+        assert_eq!(format_elapsed(Some(90_061)), "25:01:01");
+        assert_eq!(format_elapsed(None), "-");
+    }
+}
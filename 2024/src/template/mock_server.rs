@@ -0,0 +1,407 @@
+/// A local stand-in for the Advent of Code website, used to test downloads, reads and submissions offline.
+/// Serves canned inputs, puzzle pages, submission responses and leaderboards from a fixture directory:
+///
+/// ```text
+/// fixtures/
+///   leaderboard.json      private leaderboard, served for every leaderboard id.
+///   01/input.txt          puzzle input.
+///   01/part-1.html        description of part one, shown to everyone.
+///   01/part-2.html        description of part two, shown once part one is solved.
+///   01/answers.txt        accepted answers, one line per part.
+/// ```
+///
+/// Requests need the configured session cookie, except for the puzzle and events pages which are public.
+/// The events page shows the name of the logged in user, [`USER_NAME`].
+/// The server is year-agnostic and keeps the solved parts and cooldowns in memory.
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use tiny_http::{Header, Method, Request, Response, Server};
+
+/// Name of the account the configured session cookie belongs to.
+pub const USER_NAME: &str = "Mock Owner";
+
+/// Time to wait after a wrong answer, as on the real website.
+pub const DEFAULT_COOLDOWN: Duration = Duration::from_secs(60);
+
+#[derive(Default)]
+struct State {
+    /// Number of solved parts per day.
+    solved: HashMap<u8, u8>,
+    /// End of the cooldown after a wrong answer.
+    locked_until: Option<Instant>,
+}
+
+struct Fixtures {
+    dir: PathBuf,
+    session: String,
+    cooldown: Duration,
+    state: Mutex<State>,
+}
+
+/// A running mock server. The server is shut down when this is dropped.
+pub struct MockServer {
+    server: Arc<Server>,
+    url: String,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl MockServer {
+    /// Start a server on a random local port.
+    pub fn start(fixtures: &Path, session: &str) -> io::Result<Self> {
+        Self::bind("127.0.0.1:0", fixtures, session, DEFAULT_COOLDOWN)
+    }
+
+    pub fn bind(
+        addr: &str,
+        fixtures: &Path,
+        session: &str,
+        cooldown: Duration,
+    ) -> io::Result<Self> {
+        let server = Arc::new(Server::http(addr).map_err(io::Error::other)?);
+
+        let url = match server.server_addr().to_ip() {
+            Some(addr) => format!("http://{addr}"),
+            None => return Err(io::Error::other("mock server is not listening on an IP.")),
+        };
+
+        let fixtures = Arc::new(Fixtures {
+            dir: fixtures.to_path_buf(),
+            session: session.to_string(),
+            cooldown,
+            state: Mutex::new(State::default()),
+        });
+
+        let handle = {
+            let server = Arc::clone(&server);
+            thread::spawn(move || {
+                for mut request in server.incoming_requests() {
+                    let response = fixtures.handle(&mut request);
+                    // the client may have gone away, there is nobody to report this to.
+                    let _ = request.respond(response);
+                }
+            })
+        };
+
+        Ok(MockServer {
+            server,
+            url,
+            handle: Some(handle),
+        })
+    }
+
+    /// Base URL of the server, e.g. `http://127.0.0.1:34567`.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Block until the server is shut down.
+    pub fn join(mut self) {
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.server.unblock();
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+type HttpResponse = Response<io::Cursor<Vec<u8>>>;
+
+/// Routes of the website, with the day they refer to.
+#[derive(Debug, PartialEq, Eq)]
+enum Route {
+    Events,
+    Puzzle(u8),
+    Input(u8),
+    Answer(u8),
+    Leaderboard,
+}
+
+fn parse_route(method: &Method, url: &str) -> Option<Route> {
+    let path = url.split('?').next()?;
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+
+    match (method, segments.as_slice()) {
+        (Method::Get, ["events"]) => Some(Route::Events),
+        (Method::Get, [_, "day", day]) => Some(Route::Puzzle(day.parse().ok()?)),
+        (Method::Get, [_, "day", day, "input"]) => Some(Route::Input(day.parse().ok()?)),
+        (Method::Post, [_, "day", day, "answer"]) => Some(Route::Answer(day.parse().ok()?)),
+        (Method::Get, [_, "leaderboard", "private", "view", id]) if id.ends_with(".json") => {
+            Some(Route::Leaderboard)
+        }
+        _ => None,
+    }
+}
+
+/// Wraps a message in a page, the way the website renders submission responses.
+fn page(content: &str) -> HttpResponse {
+    html(
+        200,
+        &format!("<html><body><main>\n{content}\n</main></body></html>"),
+    )
+}
+
+fn message(text: &str) -> HttpResponse {
+    page(&format!("<article><p>{text}</p></article>"))
+}
+
+fn html(status: u16, body: &str) -> HttpResponse {
+    let header = Header::from_bytes("Content-Type", "text/html; charset=utf-8").unwrap();
+    Response::from_string(body)
+        .with_status_code(status)
+        .with_header(header)
+}
+
+fn text(status: u16, body: &str) -> HttpResponse {
+    Response::from_string(body).with_status_code(status)
+}
+
+fn not_found() -> HttpResponse {
+    text(404, "404 Not Found")
+}
+
+/// Parse a `application/x-www-form-urlencoded` body.
+fn parse_form(body: &str) -> HashMap<String, String> {
+    body.split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(key, value)| (decode(key), decode(value)))
+        .collect()
+}
+
+fn decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => out.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).ok();
+                match hex.and_then(|hex| u8::from_str_radix(hex, 16).ok()) {
+                    Some(byte) => {
+                        out.push(byte);
+                        i += 2;
+                    }
+                    None => out.push(b'%'),
+                }
+            }
+            byte => out.push(byte),
+        }
+        i += 1;
+    }
+
+    String::from_utf8_lossy(&out).to_string()
+}
+
+impl Fixtures {
+    fn day_file(&self, day: u8, name: &str) -> Option<String> {
+        fs::read_to_string(self.dir.join(format!("{day:02}")).join(name)).ok()
+    }
+
+    fn is_authenticated(&self, request: &Request) -> bool {
+        request
+            .headers()
+            .iter()
+            .filter(|h| h.field.equiv("Cookie"))
+            .flat_map(|h| h.value.as_str().split(';'))
+            .any(|cookie| cookie.trim() == format!("session={}", self.session))
+    }
+
+    fn handle(&self, request: &mut Request) -> HttpResponse {
+        let Some(route) = parse_route(request.method(), request.url()) else {
+            return not_found();
+        };
+
+        let authenticated = self.is_authenticated(request);
+
+        match route {
+            Route::Events if authenticated => page(&format!(
+                "<header><div class=\"user\">{USER_NAME} <span class=\"star-count\">{}*</span></div></header>",
+                self.state.lock().unwrap().solved.values().sum::<u8>()
+            )),
+            Route::Events => page("<header><a href=\"/auth/login\">[Log In]</a></header>"),
+            Route::Puzzle(day) => self.puzzle(day, authenticated),
+            Route::Input(day) if authenticated => match self.day_file(day, "input.txt") {
+                Some(input) => text(200, &input),
+                None => not_found(),
+            },
+            Route::Input(_) => text(
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+            ),
+            Route::Answer(day) if authenticated => {
+                let mut body = String::new();
+                if request.as_reader().read_to_string(&mut body).is_err() {
+                    return text(400, "Bad request");
+                }
+                self.answer(day, &parse_form(&body))
+            }
+            Route::Leaderboard if authenticated => {
+                match fs::read_to_string(self.dir.join("leaderboard.json")) {
+                    Ok(json) => text(200, &json),
+                    Err(_) => not_found(),
+                }
+            }
+            Route::Answer(_) | Route::Leaderboard => text(400, "Please log in."),
+        }
+    }
+
+    fn puzzle(&self, day: u8, authenticated: bool) -> HttpResponse {
+        let Some(part_1) = self.day_file(day, "part-1.html") else {
+            return not_found();
+        };
+
+        let solved = if authenticated {
+            self.state
+                .lock()
+                .unwrap()
+                .solved
+                .get(&day)
+                .copied()
+                .unwrap_or(0)
+        } else {
+            0
+        };
+
+        let answers = self.answers(day);
+        let mut content = format!("<article class=\"day-desc\">{part_1}</article>");
+
+        if solved >= 1 {
+            content.push_str(&format!(
+                "\n<p>Your puzzle answer was <code>{}</code>.</p>",
+                answers.first().map_or("", String::as_str)
+            ));
+            if let Some(part_2) = self.day_file(day, "part-2.html") {
+                content.push_str(&format!("\n<article class=\"day-desc\">{part_2}</article>"));
+            }
+        }
+
+        if solved >= 2 {
+            content.push_str(&format!(
+                "\n<p>Your puzzle answer was <code>{}</code>.</p>",
+                answers.get(1).map_or("", String::as_str)
+            ));
+        }
+
+        page(&content)
+    }
+
+    fn answers(&self, day: u8) -> Vec<String> {
+        self.day_file(day, "answers.txt")
+            .unwrap_or_default()
+            .lines()
+            .map(|line| line.trim().to_string())
+            .collect()
+    }
+
+    fn answer(&self, day: u8, form: &HashMap<String, String>) -> HttpResponse {
+        let answers = self.answers(day);
+        if answers.is_empty() {
+            return not_found();
+        }
+
+        let (Some(level), Some(answer)) = (
+            form.get("level").and_then(|l| l.parse::<u8>().ok()),
+            form.get("answer"),
+        ) else {
+            return text(400, "Bad request");
+        };
+
+        let mut state = self.state.lock().unwrap();
+        let solved = state.solved.get(&day).copied().unwrap_or(0);
+        let back = format!("<a href=\"/day/{day}\">[Return to Day {day}]</a>");
+
+        if level != solved + 1 {
+            return message(&format!(
+                "You don't seem to be solving the right level.  Did you already complete it? {back}"
+            ));
+        }
+
+        let now = Instant::now();
+        if let Some(locked_until) = state.locked_until.filter(|until| *until > now) {
+            let left = (locked_until - now).as_secs().max(1);
+            return message(&format!(
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have {left}s left to wait. {back}"
+            ));
+        }
+
+        let expected = answers
+            .get(usize::from(level) - 1)
+            .map_or("", String::as_str);
+
+        if answer.trim() == expected {
+            state.solved.insert(day, level);
+            return message(&format!(
+                "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to finding the Chief Historian. <a href=\"/day/{day}#part2\">[Continue to Part Two]</a>"
+            ));
+        }
+
+        state.locked_until = Some(now + self.cooldown);
+
+        let hint = match (answer.trim().parse::<i128>(), expected.parse::<i128>()) {
+            (Ok(given), Ok(expected)) if given > expected => "  your answer is too high.",
+            (Ok(given), Ok(expected)) if given < expected => "  your answer is too low.",
+            _ => "",
+        };
+
+        message(&format!(
+            "That's not the right answer;{hint}  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again. {back}"
+        ))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tiny_http::Method;
+
+    use super::{decode, parse_form, parse_route, Route};
+
+    #[test]
+    fn parses_routes() {
+        assert_eq!(
+            parse_route(&Method::Get, "/2024/day/1"),
+            Some(Route::Puzzle(1))
+        );
+        assert_eq!(
+            parse_route(&Method::Get, "/2024/day/12/input"),
+            Some(Route::Input(12))
+        );
+        assert_eq!(
+            parse_route(&Method::Post, "/2024/day/3/answer"),
+            Some(Route::Answer(3))
+        );
+        assert_eq!(
+            parse_route(&Method::Get, "/2024/leaderboard/private/view/123.json"),
+            Some(Route::Leaderboard)
+        );
+        assert_eq!(parse_route(&Method::Get, "/events"), Some(Route::Events));
+        assert_eq!(parse_route(&Method::Post, "/2024/day/1"), None);
+    }
+
+    #[test]
+    fn parses_forms() {
+        let form = parse_form("level=1&answer=a+b%2Bc");
+        assert_eq!(form["level"], "1");
+        assert_eq!(form["answer"], "a b+c");
+        assert_eq!(decode("100%"), "100%");
+        assert_eq!(decode("%zz€"), "%zz€");
+    }
+}
@@ -0,0 +1,101 @@
+/// Module for named account profiles, e.g. to run solutions against the inputs of colleagues.
+/// The active profile is read from `AOC_PROFILE`, so it carries over to the solution binaries.
+/// Inputs, accepted answers and submissions of a profile are stored in a subdirectory named after it,
+/// e.g. `data/inputs/alice/01.txt`. Without a profile, the default locations are used.
+use std::{env, fs};
+
+use crate::template::puzzle;
+
+pub static ENV_PROFILE: &str = "AOC_PROFILE";
+
+/// The active profile, if any.
+pub fn current() -> Option<String> {
+    env::var(ENV_PROFILE).ok().filter(|p| !p.is_empty())
+}
+
+/// Make a profile the active one, for this process and the ones it spawns.
+pub fn activate(profile: Option<&str>) -> Result<(), String> {
+    match profile {
+        Some(profile) => {
+            validate(profile)?;
+            env::set_var(ENV_PROFILE, profile);
+        }
+        None => env::remove_var(ENV_PROFILE),
+    }
+    Ok(())
+}
+
+/// Profile names are used in paths and environment variables, e.g. `alice` or `team-bob`.
+pub fn validate(profile: &str) -> Result<(), String> {
+    let valid = !profile.is_empty()
+        && profile
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+
+    if valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "invalid profile `{profile}`, expecting letters, digits, `-` and `_`."
+        ))
+    }
+}
+
+/// Path of a data file of the active year for a profile, e.g. `data/inputs/alice/01.txt`.
+#[must_use]
+pub fn data_path(folder: &str, file: &str, profile: Option<&str>) -> String {
+    let data = puzzle::data_dir();
+    match profile {
+        Some(profile) => format!("{data}/{folder}/{profile}/{file}"),
+        None => format!("{data}/{folder}/{file}"),
+    }
+}
+
+/// Profiles with stored inputs, in alphabetical order.
+pub fn stored() -> Vec<String> {
+    let mut profiles: Vec<String> = fs::read_dir(format!("{}/inputs", puzzle::data_dir()))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| validate(name).is_ok())
+        .collect();
+
+    profiles.sort();
+    profiles
+}
+
+/// Name of the environment variable with the session of a profile, e.g. `ADVENT_OF_CODE_SESSION_TEAM_BOB`.
+#[must_use]
+pub fn session_var(base: &str, profile: &str) -> String {
+    format!("{base}_{}", profile.to_uppercase().replace('-', "_"))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{data_path, session_var, validate};
+
+    #[test]
+    fn builds_profile_paths() {
+        assert_eq!(data_path("inputs", "01.txt", None), "data/inputs/01.txt");
+        assert_eq!(
+            data_path("answers", "01-2.txt", Some("alice")),
+            "data/answers/alice/01-2.txt"
+        );
+        assert_eq!(
+            session_var("ADVENT_OF_CODE_SESSION", "team-bob"),
+            "ADVENT_OF_CODE_SESSION_TEAM_BOB"
+        );
+    }
+
+    #[test]
+    fn validates_profile_names() {
+        assert!(validate("alice").is_ok());
+        assert!(validate("team_bob-2").is_ok());
+        assert!(validate("").is_err());
+        assert!(validate("../alice").is_err());
+    }
+}
@@ -0,0 +1,54 @@
+/// Module that renders links to the badges written to the `assets` directory.
+use crate::template::layout::Layout;
+use crate::template::puzzle::Year;
+use crate::template::readme::Renderer;
+
+pub static SECTION: &str = "badges";
+
+/// Badges linked by the section, as file name and alt text.
+static BADGES: [(&str, &str); 3] = [
+    ("stars", "Stars"),
+    ("days", "Days solved"),
+    ("runtime", "Total runtime"),
+];
+
+/// Renders the `badges` sections.
+pub struct Badges;
+
+impl Renderer for Badges {
+    fn name(&self) -> &str {
+        SECTION
+    }
+
+    fn render(&self, layout: &Layout, _: Option<Year>) -> Result<Vec<String>, String> {
+        let badges: Vec<String> = BADGES
+            .iter()
+            .map(|(name, alt)| format!("![{alt}]({})", layout.badge_link(name)))
+            .collect();
+
+        Ok(vec![badges.join(" ")])
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::Path;
+
+    use super::{Badges, SECTION};
+    use crate::template::{
+        layout::Layout,
+        puzzle::Year,
+        readme::{year_marker, Engine},
+    };
+
+    #[test]
+    fn links_badges_of_year() {
+        let layout = Layout::new(Path::new("/repo"), Path::new("/repo/2024"), Year::new(2024));
+        let marker = year_marker(SECTION, 2024);
+        let s = format!("{marker}{marker}");
+        let s = Engine::new().register(&Badges).render(&s, &layout).unwrap();
+        assert!(s.contains("\n![Stars](./assets/2024/stars.svg) ![Days solved](./assets/2024/days.svg) ![Total runtime](./assets/2024/runtime.svg)\n"));
+    }
+}
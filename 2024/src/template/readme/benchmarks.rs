@@ -0,0 +1,206 @@
+/// Module that renders the benchmark table of the readme.
+/// The approach taken is similar to how `aoc-readme-stars` handles this.
+use crate::template::columns::BenchmarkTable;
+use crate::template::description::read_title;
+use crate::template::layout::Layout;
+use crate::template::puzzle::Year;
+use crate::template::readme::Renderer;
+use crate::template::timings::Timings;
+
+pub static SECTION: &str = "benchmarking table";
+
+/// Renders the `benchmarking table` sections.
+pub struct Benchmarks<'a> {
+    pub timings: &'a Timings,
+    pub table: &'a BenchmarkTable,
+}
+
+impl Renderer for Benchmarks<'_> {
+    fn name(&self) -> &str {
+        SECTION
+    }
+
+    fn render(&self, layout: &Layout, year: Option<Year>) -> Result<Vec<String>, String> {
+        Ok(construct_table(
+            "##",
+            year,
+            layout,
+            self.table,
+            self.timings,
+        ))
+    }
+}
+
+fn construct_table(
+    prefix: &str,
+    year: Option<Year>,
+    layout: &Layout,
+    table: &BenchmarkTable,
+    timings: &Timings,
+) -> Vec<String> {
+    let header = match year {
+        // every year has its own section, so the year is part of the heading.
+        Some(year) => format!("{prefix} {year} Benchmarks"),
+        None => format!("{prefix} Benchmarks"),
+    };
+
+    let mut lines: Vec<String> = vec![header, String::new()];
+
+    lines.extend(table.to_markdown(timings, &read_title, layout));
+
+    lines.push(String::new());
+    lines.push(format!("**Total: {:.2}ms**", timings.total_millis()));
+
+    lines
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::Path;
+
+    use super::{Benchmarks, SECTION};
+    use crate::template::readme::{marker, year_marker, Engine, Error};
+    use crate::{
+        day, template::columns::BenchmarkTable, template::layout::Layout, template::puzzle::Year,
+        template::timings::Timing, template::timings::Timings,
+    };
+
+    fn get_mock_layout() -> Layout {
+        Layout::new(Path::new("/repo"), Path::new("/repo"), None)
+    }
+
+    fn get_mock_timings() -> Timings {
+        Timings {
+            data: vec![
+                Timing {
+                    day: day!(1),
+                    part_1: Some("10ms".into()),
+                    part_2: Some("20ms".into()),
+                    total_nanos: 3e+7,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                },
+                Timing {
+                    day: day!(2),
+                    part_1: Some("30ms".into()),
+                    part_2: Some("40ms".into()),
+                    total_nanos: 7e+7,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                },
+                Timing {
+                    day: day!(4),
+                    part_1: Some("40ms".into()),
+                    part_2: Some("50ms".into()),
+                    total_nanos: 9e+7,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                },
+            ],
+        }
+    }
+
+    fn update_content(s: &str, layout: &Layout) -> Result<String, Error> {
+        let timings = get_mock_timings();
+        let table = BenchmarkTable::default();
+        Engine::new()
+            .register(&Benchmarks {
+                timings: &timings,
+                table: &table,
+            })
+            .render(s, layout)
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_if_marker_not_present() {
+        let s = "# readme".to_string();
+        update_content(&s, &get_mock_layout()).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_if_too_many_markers_present() {
+        let s = format!("{0} {0} {0}", marker(SECTION));
+        update_content(&s, &get_mock_layout()).unwrap();
+    }
+
+    #[test]
+    fn updates_empty_benchmarks() {
+        let s = format!("foo\nbar\n{0}{0}\nbaz", marker(SECTION));
+        let s = update_content(&s, &get_mock_layout()).unwrap();
+        assert_eq!(s.contains("## Benchmarks"), true);
+    }
+
+    #[test]
+    fn updates_existing_benchmarks() {
+        let s = format!("foo\nbar\n{0}{0}\nbaz", marker(SECTION));
+        let s = update_content(&s, &get_mock_layout()).unwrap();
+        let s = update_content(&s, &get_mock_layout()).unwrap();
+        assert_eq!(s.matches(&marker(SECTION)).collect::<Vec<&str>>().len(), 2);
+        assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
+    }
+
+    #[test]
+    fn format_benchmarks() {
+        let s = format!("foo\nbar\n{0}\n{0}\nbaz", marker(SECTION));
+        let s = update_content(&s, &get_mock_layout()).unwrap();
+        let expected = [
+            "foo",
+            "bar",
+            "<!--- benchmarking table --->",
+            "## Benchmarks",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "",
+            "**Total: 190.00ms**",
+            "<!--- benchmarking table --->",
+            "baz",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn links_to_project_directory() {
+        let layout = Layout::new(Path::new("/repo"), Path::new("/repo/2024"), Year::new(2024));
+        let marker = year_marker(SECTION, 2024);
+        let s = format!("{marker}\n{marker}");
+        let s = update_content(&s, &layout).unwrap();
+        assert!(s.contains("## 2024 Benchmarks"));
+        assert!(s.contains("| [Day 1](./2024/src/bin/01.rs) | `10ms` | `20ms` |"));
+    }
+
+    #[test]
+    fn only_updates_table_of_own_year() {
+        let layout = Layout::new(Path::new("/repo"), Path::new("/repo/2024"), Year::new(2024));
+        let other = format!(
+            "{}\nother year\n{}",
+            year_marker(SECTION, 2023),
+            year_marker(SECTION, 2023)
+        );
+        let own = format!(
+            "{}{}",
+            year_marker(SECTION, 2024),
+            year_marker(SECTION, 2024)
+        );
+        let s = format!("{other}\n{own}");
+        let s = update_content(&s, &layout).unwrap();
+        assert!(s.starts_with(&other));
+        assert_eq!(s.matches("2024 Benchmarks").count(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_if_year_marker_not_present_in_multi_year_repository() {
+        let layout = Layout::new(Path::new("/repo"), Path::new("/repo/2024"), Year::new(2024));
+        let s = format!("{0}{0}", marker(SECTION));
+        update_content(&s, &layout).unwrap();
+    }
+}
@@ -0,0 +1,80 @@
+/// Module that renders a one-line summary of a year, e.g. for the `<summary>` of a collapsed section.
+use crate::template::answers::Progress;
+use crate::template::layout::Layout;
+use crate::template::puzzle::Year;
+use crate::template::readme::Renderer;
+use crate::template::timings::Timings;
+
+pub static SECTION: &str = "year details";
+
+/// Renders the `year details` sections.
+pub struct Details<'a> {
+    pub progress: &'a Progress,
+    pub timings: &'a Timings,
+}
+
+impl Renderer for Details<'_> {
+    fn name(&self) -> &str {
+        SECTION
+    }
+
+    fn render(&self, layout: &Layout, year: Option<Year>) -> Result<Vec<String>, String> {
+        let summary = format!(
+            "{} ⭐ · {} days solved · {:.2}ms total",
+            self.progress.stars(),
+            self.progress.days_solved(),
+            self.timings.total_millis()
+        );
+
+        Ok(vec![match year.or(layout.year) {
+            Some(year) => format!("**{year}**: {summary}"),
+            None => summary,
+        }])
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{collections::BTreeMap, path::Path};
+
+    use super::{Details, SECTION};
+    use crate::{
+        day,
+        template::{
+            answers::Progress,
+            layout::Layout,
+            puzzle::Year,
+            readme::{marker, Engine},
+            timings::{Timing, Timings},
+        },
+    };
+
+    #[test]
+    fn format_details() {
+        let layout = Layout::new(Path::new("/repo"), Path::new("/repo"), Year::new(2024));
+        let progress = Progress {
+            days: BTreeMap::from([(day!(1), [true, true]), (day!(2), [true, false])]),
+        };
+        let timings = Timings {
+            data: vec![Timing {
+                day: day!(1),
+                part_1: Some("1ms".into()),
+                part_2: Some("2ms".into()),
+                total_nanos: 3e+6,
+                part_1_stats: None,
+                part_2_stats: None,
+            }],
+        };
+        let s = format!("{0}{0}", marker(SECTION));
+        let s = Engine::new()
+            .register(&Details {
+                progress: &progress,
+                timings: &timings,
+            })
+            .render(&s, &layout)
+            .unwrap();
+        assert!(s.contains("\n**2024**: 3 ⭐ · 1 days solved · 3.00ms total\n"));
+    }
+}
@@ -0,0 +1,500 @@
+/// Module that renders marker-delimited sections of the readme, e.g. the benchmark and stars tables.
+/// A section starts and ends with a marker such as `<!--- benchmarking table --->`.
+/// Every section is filled by the renderer registered under its name, other sections are left untouched.
+use std::{fmt, fs, io, path::Path};
+
+use crate::template::layout::Layout;
+use crate::template::puzzle::Year;
+
+pub mod badges;
+pub mod benchmarks;
+pub mod details;
+pub mod stars;
+pub mod titles;
+
+#[derive(Debug)]
+pub enum Error {
+    /// A marker of a registered section without a closing marker.
+    Unclosed {
+        marker: String,
+        line: usize,
+    },
+    /// Two rendered sections that overlap or nest, reported at the start of the second one.
+    Overlapping {
+        outer: String,
+        inner: String,
+        line: usize,
+    },
+    /// A required section is not present in the readme.
+    Missing {
+        marker: String,
+    },
+    /// A repository with one project per year, but no year to pick a section with.
+    MissingYear {
+        name: String,
+    },
+    /// A renderer failed to render its section.
+    Render {
+        marker: String,
+        line: usize,
+        message: String,
+    },
+    IO(io::Error),
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Unclosed { marker, line } => {
+                write!(f, "line {line}: section {marker} is never closed.")
+            }
+            Error::Overlapping { outer, inner, line } => {
+                write!(f, "line {line}: section {inner} overlaps section {outer}.")
+            }
+            Error::Missing { marker } => write!(f, "could not find section {marker}."),
+            Error::MissingYear { name } => write!(
+                f,
+                "AOC_YEAR is required to locate the {name} section of this year."
+            ),
+            Error::Render {
+                marker,
+                line,
+                message,
+            } => write!(
+                f,
+                "line {line}: failed to render section {marker}: {message}"
+            ),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// Fills the sections with a given name.
+pub trait Renderer {
+    /// Name of the sections, e.g. `benchmarking table`.
+    fn name(&self) -> &str;
+
+    /// Render the content of a section. `year` is set for per-year sections.
+    fn render(&self, layout: &Layout, year: Option<Year>) -> Result<Vec<String>, String>;
+}
+
+/// Returns the marker of a section, e.g. `<!--- benchmarking table --->`.
+pub fn marker(name: &str) -> String {
+    format!("<!--- {name} --->")
+}
+
+/// Returns the marker of a section for a year, e.g. `<!--- benchmarking table 2024 --->`.
+pub fn year_marker(name: &str, year: u16) -> String {
+    format!("<!--- {name} {year} --->")
+}
+
+/* -------------------------------------------------------------------------- */
+
+static MARKER_START: &str = "<!---";
+static MARKER_END: &str = "--->";
+
+/// A marker found in the readme, with its byte range and 1-based line.
+#[derive(Clone, Debug)]
+struct Marker<'a> {
+    name: &'a str,
+    start: usize,
+    end: usize,
+    line: usize,
+}
+
+impl Marker<'_> {
+    fn text(&self) -> String {
+        marker(self.name)
+    }
+}
+
+/// A pair of markers with the same name.
+#[derive(Clone, Debug)]
+struct Section<'a> {
+    open: Marker<'a>,
+    close: Marker<'a>,
+}
+
+fn find_markers(s: &str) -> Vec<Marker<'_>> {
+    let mut markers = vec![];
+    let mut pos = 0;
+
+    while let Some(offset) = s[pos..].find(MARKER_START) {
+        let start = pos + offset;
+        let inner = start + MARKER_START.len();
+
+        let Some(length) = s[inner..].find(MARKER_END) else {
+            break;
+        };
+
+        let end = inner + length + MARKER_END.len();
+        let name = s[inner..inner + length].trim();
+
+        if !name.is_empty() {
+            markers.push(Marker {
+                name,
+                start,
+                end,
+                line: s[..start].matches('\n').count() + 1,
+            });
+        }
+
+        pos = end;
+    }
+
+    markers
+}
+
+/// Pairs up markers with the same name in order of appearance.
+/// Returns the sections and the markers that were left without a closing marker.
+fn pair_markers(markers: Vec<Marker<'_>>) -> (Vec<Section<'_>>, Vec<Marker<'_>>) {
+    let mut sections = vec![];
+    let mut unclosed: Vec<Marker> = vec![];
+
+    for marker in markers {
+        match unclosed.iter().position(|m| m.name == marker.name) {
+            Some(i) => {
+                let open = unclosed.remove(i);
+                sections.push(Section {
+                    open,
+                    close: marker,
+                });
+            }
+            None => unclosed.push(marker),
+        }
+    }
+
+    (sections, unclosed)
+}
+
+/// Splits the year off a section name, e.g. `benchmarking table 2024`.
+fn split_year(name: &str) -> (&str, Option<Year>) {
+    match name.rsplit_once(' ') {
+        Some((base, year)) => match year.parse() {
+            Ok(year) => (base, Some(year)),
+            Err(_) => (name, None),
+        },
+        None => (name, None),
+    }
+}
+
+/// Selects the sections a renderer fills in this project, and the year they belong to.
+/// Repositories with one project per year need a marker per year, so sections of other years are never touched.
+/// Single-project repositories may use the plain marker instead, except when working on another year.
+fn select_sections<'a, 'b>(
+    sections: &'b [Section<'a>],
+    name: &str,
+    layout: &Layout,
+) -> Result<(Vec<&'b Section<'a>>, Option<Year>), Error> {
+    let with_name = |year: Option<Year>| -> Vec<&'b Section<'a>> {
+        sections
+            .iter()
+            .filter(|section| split_year(section.open.name) == (name, year))
+            .collect()
+    };
+
+    if let Some(year) = layout.year {
+        let selected = with_name(Some(year));
+        if !selected.is_empty() || layout.per_year {
+            return Ok((selected, Some(year)));
+        }
+    } else if layout.per_year {
+        return Err(Error::MissingYear {
+            name: name.to_string(),
+        });
+    }
+
+    Ok((with_name(None), None))
+}
+
+/// Expected marker of a renderer, used when reporting missing sections.
+fn expected_marker(name: &str, layout: &Layout) -> String {
+    match layout.year {
+        Some(year) if layout.per_year => year_marker(name, year.into_inner()),
+        _ => marker(name),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Renders the sections of a readme from a set of registered renderers.
+#[derive(Default)]
+pub struct Engine<'a> {
+    renderers: Vec<&'a dyn Renderer>,
+    allow_missing: bool,
+}
+
+impl<'a> Engine<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(mut self, renderer: &'a dyn Renderer) -> Self {
+        self.renderers.push(renderer);
+        self
+    }
+
+    /// Skip renderers without a section in the readme instead of failing.
+    pub fn allow_missing(mut self) -> Self {
+        self.allow_missing = true;
+        self
+    }
+
+    /// Render every registered section of `readme`, returning the updated content.
+    pub fn render(&self, readme: &str, layout: &Layout) -> Result<String, Error> {
+        let (sections, unclosed) = pair_markers(find_markers(readme));
+
+        let is_registered = |name: &str| {
+            let (name, _) = split_year(name);
+            self.renderers.iter().any(|r| r.name() == name)
+        };
+
+        if let Some(marker) = unclosed.iter().find(|m| is_registered(m.name)) {
+            return Err(Error::Unclosed {
+                marker: marker.text(),
+                line: marker.line,
+            });
+        }
+
+        let mut selected = vec![];
+
+        for renderer in &self.renderers {
+            let (matches, year) = select_sections(&sections, renderer.name(), layout)?;
+
+            if matches.is_empty() && !self.allow_missing {
+                return Err(Error::Missing {
+                    marker: expected_marker(renderer.name(), layout),
+                });
+            }
+
+            selected.extend(
+                matches
+                    .into_iter()
+                    .map(|section| (section, *renderer, year)),
+            );
+        }
+
+        selected.sort_by_key(|(section, _, _)| section.open.start);
+
+        for pair in selected.windows(2) {
+            let (outer, inner) = (pair[0].0, pair[1].0);
+            if inner.open.start < outer.close.end {
+                return Err(Error::Overlapping {
+                    outer: outer.open.text(),
+                    inner: inner.open.text(),
+                    line: inner.open.line,
+                });
+            }
+        }
+
+        let mut output = String::with_capacity(readme.len());
+        let mut pos = 0;
+
+        for (section, renderer, year) in selected {
+            let lines = renderer
+                .render(layout, year)
+                .map_err(|message| Error::Render {
+                    marker: section.open.text(),
+                    line: section.open.line,
+                    message,
+                })?;
+
+            output.push_str(&readme[pos..section.open.start]);
+            output.push_str(&readme[section.open.start..section.open.end]);
+            output.push('\n');
+            for line in lines {
+                output.push_str(&line);
+                output.push('\n');
+            }
+            output.push_str(&readme[section.close.start..section.close.end]);
+            pos = section.close.end;
+        }
+
+        output.push_str(&readme[pos..]);
+        Ok(output)
+    }
+
+    /// Render the sections of a file. The file is only written if its content changed.
+    /// Returns whether the file was written.
+    pub fn update_file(&self, path: &Path, layout: &Layout) -> Result<bool, Error> {
+        let readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+        let rendered = self.render(&readme, layout)?;
+
+        if rendered == readme {
+            return Ok(false);
+        }
+
+        fs::write(path, rendered)?;
+        Ok(true)
+    }
+
+    /// Render the sections of the readme at the root of the repository.
+    pub fn update(&self) -> Result<bool, Error> {
+        let layout = Layout::discover()?;
+        self.update_file(&layout.readme_path(), &layout)
+    }
+}
+
+/// Returns the current content of the first section a renderer named `name` would fill, if there is one.
+pub fn section<'a>(readme: &'a str, name: &str, layout: &Layout) -> Option<&'a str> {
+    let (sections, _) = pair_markers(find_markers(readme));
+    let (selected, _) = select_sections(&sections, name, layout).ok()?;
+    selected
+        .first()
+        .map(|section| &readme[section.open.end..section.close.start])
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::Path;
+
+    use super::{year_marker, Engine, Error, Renderer};
+    use crate::template::layout::Layout;
+    use crate::template::puzzle::Year;
+
+    struct Mock(&'static str);
+
+    impl Renderer for Mock {
+        fn name(&self) -> &str {
+            self.0
+        }
+
+        fn render(&self, _: &Layout, year: Option<Year>) -> Result<Vec<String>, String> {
+            Ok(vec![format!("{} {year:?}", self.0)])
+        }
+    }
+
+    struct Failing;
+
+    impl Renderer for Failing {
+        fn name(&self) -> &str {
+            "foo"
+        }
+
+        fn render(&self, _: &Layout, _: Option<Year>) -> Result<Vec<String>, String> {
+            Err("no data".into())
+        }
+    }
+
+    fn get_mock_layout() -> Layout {
+        Layout::new(Path::new("/repo"), Path::new("/repo"), Year::new(2024))
+    }
+
+    #[test]
+    fn replaces_plain_sections() {
+        let s = "a\n<!--- foo --->\nold\n<!--- foo --->\nb";
+        let result = Engine::new()
+            .register(&Mock("foo"))
+            .render(s, &get_mock_layout())
+            .unwrap();
+        assert_eq!(result, "a\n<!--- foo --->\nfoo None\n<!--- foo --->\nb");
+    }
+
+    #[test]
+    fn renders_multiple_sections() {
+        let s = "<!--- foo ---><!--- foo --->\n<!--- bar --->old<!--- bar --->\n<!--- foo ---><!--- foo --->";
+        let result = Engine::new()
+            .register(&Mock("foo"))
+            .register(&Mock("bar"))
+            .render(s, &get_mock_layout())
+            .unwrap();
+        assert_eq!(result.matches("foo None").count(), 2);
+        assert_eq!(result.matches("bar None").count(), 1);
+        assert!(!result.contains("old"));
+    }
+
+    #[test]
+    fn leaves_unknown_sections_alone() {
+        let s = "<!--- baz --->keep<!--- baz --->\n<!--- foo ---><!--- foo --->\n<!--- note --->";
+        let result = Engine::new()
+            .register(&Mock("foo"))
+            .render(s, &get_mock_layout())
+            .unwrap();
+        assert!(result.starts_with("<!--- baz --->keep<!--- baz --->"));
+        assert!(result.ends_with("<!--- note --->"));
+    }
+
+    #[test]
+    fn prefers_year_sections() {
+        let s = format!("{0}{0}", year_marker("foo", 2024));
+        let result = Engine::new()
+            .register(&Mock("foo"))
+            .render(&s, &get_mock_layout())
+            .unwrap();
+        assert!(result.contains("foo Some(Year(2024))"));
+    }
+
+    #[test]
+    fn reports_unclosed_sections() {
+        let s = "a\n<!--- foo --->\nb\n<!--- foo --->\n<!--- foo --->";
+        let result = Engine::new()
+            .register(&Mock("foo"))
+            .render(s, &get_mock_layout());
+        assert!(matches!(result, Err(Error::Unclosed { line: 5, .. })));
+    }
+
+    #[test]
+    fn reports_overlapping_sections() {
+        let s = "<!--- foo --->\n<!--- bar --->\n<!--- foo --->\n<!--- bar --->";
+        let result = Engine::new()
+            .register(&Mock("foo"))
+            .register(&Mock("bar"))
+            .render(s, &get_mock_layout());
+        assert!(matches!(result, Err(Error::Overlapping { line: 2, .. })));
+    }
+
+    #[test]
+    fn reports_missing_sections() {
+        let engine = Engine::new().register(&Mock("foo"));
+        assert!(matches!(
+            engine.render("# readme", &get_mock_layout()),
+            Err(Error::Missing { .. })
+        ));
+        assert_eq!(
+            engine
+                .allow_missing()
+                .render("# readme", &get_mock_layout())
+                .unwrap(),
+            "# readme"
+        );
+    }
+
+    #[test]
+    fn reports_render_errors() {
+        let s = "# readme\n\n<!--- foo ---><!--- foo --->";
+        let result = Engine::new()
+            .register(&Failing)
+            .render(s, &get_mock_layout());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 3: failed to render section <!--- foo --->: no data"
+        );
+    }
+
+    #[test]
+    fn errors_without_year_in_multi_year_repository() {
+        let layout = Layout::new(Path::new("/repo"), Path::new("/repo/2024"), None);
+        let result = Engine::new()
+            .register(&Mock("foo"))
+            .render("<!--- foo ---><!--- foo --->", &layout);
+        assert!(matches!(result, Err(Error::MissingYear { .. })));
+    }
+
+    #[test]
+    fn is_idempotent() {
+        let engine = Engine::new().register(&Mock("foo"));
+        let once = engine
+            .render("a\n<!--- foo --->\n<!--- foo --->\nb", &get_mock_layout())
+            .unwrap();
+        let twice = engine.render(&once, &get_mock_layout()).unwrap();
+        assert_eq!(once, twice);
+    }
+}
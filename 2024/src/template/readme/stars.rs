@@ -0,0 +1,136 @@
+/// Module that updates the readme with the progress table.
+/// The table has the same format as the one written by the `advent-readme-stars` action,
+/// but is generated from the answers recorded in `data/answers`.
+use std::fs;
+
+use crate::template::answers::Progress;
+use crate::template::aoc_client::DEFAULT_BASE_URL;
+use crate::template::layout::Layout;
+use crate::template::puzzle::{Puzzle, Year};
+use crate::template::readme::{section, Renderer};
+
+pub static SECTION: &str = "advent_readme_stars table";
+
+/// Renders the `advent_readme_stars table` sections.
+pub struct Stars<'a> {
+    pub progress: &'a Progress,
+}
+
+impl Renderer for Stars<'_> {
+    fn name(&self) -> &str {
+        SECTION
+    }
+
+    fn render(&self, layout: &Layout, year: Option<Year>) -> Result<Vec<String>, String> {
+        let year = year
+            .or(layout.year)
+            .ok_or("AOC_YEAR is required to link to the puzzles in the README.")?;
+        Ok(construct_table("##", year, self.progress))
+    }
+}
+
+/// Number of stars the stars section of a readme shows, if it has one.
+pub fn shown(readme: &str, layout: &Layout) -> Option<usize> {
+    section(readme, SECTION, layout).map(|content| content.matches('⭐').count())
+}
+
+/// Number of stars the readme at the root of the repository shows, if it has a stars section.
+pub fn read_shown() -> Option<usize> {
+    let layout = Layout::discover().ok()?;
+    let readme = fs::read_to_string(layout.readme_path()).ok()?;
+    shown(&readme, &layout)
+}
+
+fn construct_table(prefix: &str, year: Year, progress: &Progress) -> Vec<String> {
+    let mut lines: Vec<String> = vec![
+        format!("{prefix} {year} Results"),
+        String::new(),
+        "| Day | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: |".into(),
+    ];
+
+    for (day, parts) in &progress.days {
+        let [part_1, part_2] = parts.map(|solved| if solved { "⭐" } else { " " });
+        lines.push(format!(
+            "| [Day {}]({}) | {part_1} | {part_2} |",
+            day.into_inner(),
+            Puzzle::new(year, *day).url(DEFAULT_BASE_URL),
+        ));
+    }
+
+    lines
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{collections::BTreeMap, path::Path};
+
+    use super::{shown, Stars, SECTION};
+    use crate::{
+        day,
+        template::{
+            answers::Progress,
+            layout::Layout,
+            puzzle::Year,
+            readme::{year_marker, Engine, Error},
+        },
+    };
+
+    fn update_content(s: &str, layout: &Layout) -> Result<String, Error> {
+        let progress = get_mock_progress();
+        Engine::new()
+            .register(&Stars {
+                progress: &progress,
+            })
+            .render(s, layout)
+    }
+
+    fn get_mock_progress() -> Progress {
+        Progress {
+            days: BTreeMap::from([(day!(1), [true, true]), (day!(2), [true, false])]),
+        }
+    }
+
+    #[test]
+    fn format_stars() {
+        let layout = Layout::new(Path::new("/repo"), Path::new("/repo/2024"), Year::new(2024));
+        let marker = year_marker(SECTION, 2024);
+        let s = format!("foo\n{marker}\n{marker}\nbar");
+        let s = update_content(&s, &layout).unwrap();
+        let expected = [
+            "foo",
+            "<!--- advent_readme_stars table 2024 --->",
+            "## 2024 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2024/day/1) | ⭐ | ⭐ |",
+            "| [Day 2](https://adventofcode.com/2024/day/2) | ⭐ |   |",
+            "<!--- advent_readme_stars table 2024 --->",
+            "bar",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_without_year() {
+        let layout = Layout::new(Path::new("/repo"), Path::new("/repo"), None);
+        let s = "<!--- advent_readme_stars table ---><!--- advent_readme_stars table --->";
+        update_content(s, &layout).unwrap();
+    }
+
+    #[test]
+    fn counts_shown_stars() {
+        let layout = Layout::new(Path::new("/repo"), Path::new("/repo/2024"), Year::new(2024));
+        let marker = year_marker(SECTION, 2024);
+        let s = format!(
+            "⭐\n{marker}\n| [Day 1](url) | ⭐ | ⭐ |\n| [Day 2](url) | ⭐ |   |\n{marker}\n⭐"
+        );
+        assert_eq!(shown(&s, &layout), Some(3));
+        assert_eq!(shown("no table", &layout), None);
+    }
+}
@@ -0,0 +1,270 @@
+/// Module that finds and stores the Advent of Code session cookie.
+/// The cookie is read from `ADVENT_OF_CODE_SESSION`, or from an `adventofcode.session` file in the home
+/// or config directory. These are the same locations `aoc-cli` reads. For a profile, e.g. `alice`,
+/// these are `ADVENT_OF_CODE_SESSION_ALICE` and `adventofcode.alice.session`.
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use crate::template::profile;
+
+pub static ENV_SESSION: &str = "ADVENT_OF_CODE_SESSION";
+
+/// Name of the session cookie file, in the home or config directory.
+static SESSION_FILE_NAME: &str = "adventofcode.session";
+
+/// Approximate lifetime of a session cookie.
+pub const LIFETIME: Duration = Duration::from_secs(30 * 86400);
+
+/// Time before the approximate expiry from which on to warn about it.
+pub const EXPIRY_WARNING: Duration = Duration::from_secs(5 * 86400);
+
+/// Files larger than this are not searched for a leaked cookie.
+const MAX_SCANNED_FILE_SIZE: u64 = 1024 * 1024;
+
+/// Where a session cookie was found.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    Env(String),
+    File(PathBuf),
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Env(var) => write!(f, "environment variable {var}"),
+            Source::File(path) => write!(f, "file \"{}\"", path.display()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Session {
+    pub token: String,
+    pub source: Source,
+}
+
+impl Session {
+    /// Time since the cookie was stored, if it was read from a file.
+    pub fn age(&self) -> Option<Duration> {
+        match &self.source {
+            Source::File(path) => {
+                let modified = fs::metadata(path).and_then(|m| m.modified()).ok()?;
+                SystemTime::now().duration_since(modified).ok()
+            }
+            Source::Env(_) => None,
+        }
+    }
+}
+
+/// Find the session cookie of the active profile.
+pub fn find() -> Option<Session> {
+    let profile = profile::current();
+    let var = var_name(profile.as_deref());
+
+    if let Some(token) = env::var(&var).ok().and_then(|s| parse(&s)) {
+        return Some(Session {
+            token,
+            source: Source::Env(var),
+        });
+    }
+
+    files(&file_name(profile.as_deref()))
+        .into_iter()
+        .find_map(|path| {
+            let token = parse(&fs::read_to_string(&path).ok()?)?;
+            Some(Session {
+                token,
+                source: Source::File(path),
+            })
+        })
+}
+
+/// Store the session cookie of the active profile in the config directory, readable only by the current user.
+pub fn store(token: &str) -> io::Result<PathBuf> {
+    let dir = config_dir().ok_or_else(|| io::Error::other("no home or config directory found."))?;
+    fs::create_dir_all(&dir)?;
+
+    let path = dir.join(file_name(profile::current().as_deref()));
+    write_private(&path, &format!("{token}\n"))?;
+    Ok(path)
+}
+
+#[cfg(unix)]
+fn write_private(path: &Path, contents: &str) -> io::Result<()> {
+    use std::{
+        io::Write,
+        os::unix::fs::{OpenOptionsExt, PermissionsExt},
+    };
+
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)?;
+    // the mode only applies to new files.
+    file.set_permissions(fs::Permissions::from_mode(0o600))?;
+    file.write_all(contents.as_bytes())
+}
+
+#[cfg(not(unix))]
+fn write_private(path: &Path, contents: &str) -> io::Result<()> {
+    fs::write(path, contents)
+}
+
+/// Name of the environment variable with the cookie of a profile.
+#[must_use]
+pub fn var_name(profile: Option<&str>) -> String {
+    match profile {
+        Some(profile) => profile::session_var(ENV_SESSION, profile),
+        None => ENV_SESSION.to_string(),
+    }
+}
+
+/// Name of the cookie file of a profile, e.g. `adventofcode.alice.session`.
+#[must_use]
+pub fn file_name(profile: Option<&str>) -> String {
+    match profile {
+        Some(profile) => SESSION_FILE_NAME.replace(".session", &format!(".{profile}.session")),
+        None => SESSION_FILE_NAME.to_string(),
+    }
+}
+
+fn home_dir() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+}
+
+fn config_dir() -> Option<PathBuf> {
+    env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| home_dir().map(|home| home.join(".config")))
+}
+
+/// Cookie files in the order they are read: `~/.<name>`, then `<config>/<name>`.
+pub fn files(name: &str) -> Vec<PathBuf> {
+    let mut files = vec![];
+    if let Some(home) = home_dir() {
+        files.push(home.join(format!(".{name}")));
+    }
+    if let Some(config) = config_dir() {
+        files.push(config.join(name));
+    }
+    files
+}
+
+/// Parse a session cookie, accepting both the bare value and `session=<value>`.
+pub fn parse(s: &str) -> Option<String> {
+    let session = s.trim();
+    let session = session.strip_prefix("session=").unwrap_or(session);
+    (!session.is_empty()).then(|| session.to_string())
+}
+
+/// The command that stores a new cookie for the active profile.
+/// `login` is a built-in command of cargo, so it is a subcommand of `auth`.
+pub fn login_command() -> String {
+    match profile::current() {
+        Some(profile) => format!("cargo auth login --profile {profile}"),
+        None => "cargo auth login".into(),
+    }
+}
+
+/// Time left until the approximate expiry of a cookie of the given age.
+pub fn expires_in(age: Duration) -> Option<Duration> {
+    LIFETIME.checked_sub(age)
+}
+
+/// The git repository the working directory is in, if any.
+pub fn repository_root() -> Option<PathBuf> {
+    let cwd = env::current_dir().ok()?;
+    cwd.ancestors()
+        .find(|dir| dir.join(".git").exists())
+        .map(Path::to_path_buf)
+}
+
+/// Files below `dir` that contain the cookie. Build output and git internals are skipped.
+pub fn find_leaks(dir: &Path, token: &str) -> Vec<PathBuf> {
+    let mut leaks = vec![];
+    let Ok(entries) = fs::read_dir(dir) else {
+        return leaks;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+
+        if file_type.is_dir() {
+            if !matches!(entry.file_name().to_str(), Some(".git" | "target")) {
+                leaks.extend(find_leaks(&path, token));
+            }
+        } else if file_type.is_file()
+            && entry
+                .metadata()
+                .is_ok_and(|m| m.len() <= MAX_SCANNED_FILE_SIZE)
+            && fs::read(&path).is_ok_and(|contents| contains(&contents, token.as_bytes()))
+        {
+            leaks.push(path);
+        }
+    }
+
+    leaks
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    !needle.is_empty()
+        && haystack
+            .windows(needle.len())
+            .any(|window| window == needle)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs, time::Duration};
+
+    use super::{expires_in, file_name, find_leaks, parse, LIFETIME};
+
+    #[test]
+    fn parses_session_cookies() {
+        assert_eq!(parse("abc123\n"), Some("abc123".into()));
+        assert_eq!(parse("session=abc123"), Some("abc123".into()));
+        assert_eq!(parse("  \n"), None);
+    }
+
+    #[test]
+    fn names_cookie_files() {
+        assert_eq!(file_name(None), "adventofcode.session");
+        assert_eq!(file_name(Some("alice")), "adventofcode.alice.session");
+    }
+
+    #[test]
+    fn estimates_expiry() {
+        let day = Duration::from_secs(86400);
+        assert_eq!(expires_in(day), Some(LIFETIME - day));
+        assert_eq!(expires_in(LIFETIME + day), None);
+    }
+
+    #[test]
+    fn finds_leaked_cookies() {
+        let dir = env::temp_dir().join(format!("aoc-session-leaks-{}", std::process::id()));
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        fs::create_dir_all(dir.join("target")).unwrap();
+        fs::write(dir.join("nested/.env"), "SESSION=53616c7465645f5f").unwrap();
+        fs::write(dir.join("target/cache"), "53616c7465645f5f").unwrap();
+        fs::write(dir.join("notes.md"), "nothing to see").unwrap();
+
+        let leaks = find_leaks(&dir, "53616c7465645f5f");
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(leaks, vec![dir.join("nested/.env")]);
+    }
+}
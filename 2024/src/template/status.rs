@@ -0,0 +1,345 @@
+/// Module that collects where each day of the active year stands, and renders it as a calendar.
+/// Everything is read from the data directory, except the results of the example tests, which are run.
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, Read},
+    path::Path,
+    process::{Command, Stdio},
+};
+
+use crate::template::aoc_client::days_from_civil;
+use crate::template::description::get_puzzle_path;
+use crate::template::examples::get_example_paths;
+use crate::template::ledger::{Ledger, Verdict};
+use crate::template::puzzle::{Puzzle, Year};
+use crate::template::run_multi::get_path_for_bin;
+use crate::template::timings::{format_nanos, Timings};
+use crate::template::{answers, profile, Day, ANSI_BOLD, ANSI_RESET};
+
+/// Width of a day in the calendar, without the space between days.
+const CELL_WIDTH: usize = 9;
+
+const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+/// Where a part of a puzzle stands.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PartStatus {
+    Solved,
+    /// Only wrong answers were submitted so far.
+    Attempted,
+    Open,
+}
+
+impl PartStatus {
+    fn symbol(self) -> char {
+        match self {
+            PartStatus::Solved => '★',
+            PartStatus::Attempted => '✗',
+            PartStatus::Open => '☆',
+        }
+    }
+}
+
+/// Where a day stands.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DayStatus {
+    pub day: Day,
+    pub unlocked: bool,
+    pub scaffolded: bool,
+    /// The input exists and is not empty.
+    pub input: bool,
+    /// An example file exists and is not empty.
+    pub examples: bool,
+    /// The puzzle description was downloaded.
+    pub description: bool,
+    /// Whether the tests of the day passed, if they were run.
+    pub tests: Option<bool>,
+    pub answers: [Option<String>; 2],
+    /// Total time of the stored timing, in nanoseconds.
+    pub timing: Option<f64>,
+    pub parts: [PartStatus; 2],
+}
+
+impl DayStatus {
+    /// Read the status of a day from the data directory, as of unix time `now`.
+    pub fn read(year: Year, day: Day, timings: &Timings, now: u64) -> Self {
+        let profile = profile::current();
+        let answers = [1, 2].map(|part| answers::read(day, part, profile.as_deref()));
+        let ledger = Ledger::read(day).unwrap_or_else(|e| {
+            eprintln!("Warning: {e}");
+            Ledger::default()
+        });
+
+        let parts = [1, 2].map(|part| {
+            let submitted: Vec<_> = ledger
+                .submissions
+                .iter()
+                .filter(|s| s.part == part)
+                .collect();
+            if answers[usize::from(part - 1)].is_some()
+                || submitted.iter().any(|s| s.verdict == Verdict::Correct)
+            {
+                PartStatus::Solved
+            } else if submitted.is_empty() {
+                PartStatus::Open
+            } else {
+                PartStatus::Attempted
+            }
+        });
+
+        let input = profile::data_path("inputs", &format!("{day}.txt"), profile.as_deref());
+        let examples = get_example_paths(day, 1)
+            .into_iter()
+            .chain(get_example_paths(day, 2))
+            .any(|path| is_non_empty(&path));
+
+        DayStatus {
+            day,
+            unlocked: Puzzle::new(year, day).unlocks_at() <= now,
+            scaffolded: Path::new(&get_path_for_bin(day)).exists(),
+            input: is_non_empty(&input),
+            examples,
+            description: Path::new(&get_puzzle_path(day)).exists(),
+            tests: None,
+            answers,
+            timing: timings
+                .data
+                .iter()
+                .find(|timing| timing.day == day)
+                .map(|timing| timing.total_nanos),
+            parts,
+        }
+    }
+
+    /// Files of the day, each shown as its initial if present: solution, input, examples, description.
+    /// The result of the tests follows.
+    fn flags(&self) -> String {
+        let mut flags: String = [
+            (self.scaffolded, 'S'),
+            (self.input, 'I'),
+            (self.examples, 'E'),
+            (self.description, 'D'),
+        ]
+        .iter()
+        .map(|(present, flag)| if *present { *flag } else { '·' })
+        .collect();
+
+        flags.push(match self.tests {
+            Some(true) => '✔',
+            Some(false) => '✘',
+            None => '·',
+        });
+        flags
+    }
+
+    /// Lines of the day in the calendar.
+    fn cell(&self) -> [String; 3] {
+        let day = self.day.to_string();
+        if !self.unlocked {
+            return [day, String::new(), String::new()];
+        }
+
+        let stars: String = self.parts.iter().map(|part| part.symbol()).collect();
+        [
+            format!("{day} {stars}"),
+            self.flags(),
+            self.timing.map(format_nanos).unwrap_or_default(),
+        ]
+    }
+}
+
+fn is_non_empty(path: &str) -> bool {
+    fs::metadata(path).is_ok_and(|m| m.len() > 0)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Run the tests of all solutions once, returns whether the tests of each day passed.
+/// Returns `None` if the tests could not be run, e.g. because a solution does not compile.
+pub fn run_tests() -> Option<HashMap<Day, bool>> {
+    // cargo reports which binary it runs on stderr, the tests report their results on stdout.
+    // both go into one pipe, so each result follows the binary it belongs to.
+    let (mut reader, writer) = io::pipe().ok()?;
+    let mut command = Command::new("cargo");
+    command
+        .args(["test", "--bins", "--no-fail-fast", "--color", "never"])
+        .stdin(Stdio::null())
+        .stdout(writer.try_clone().ok()?)
+        .stderr(writer);
+    let mut child = command.spawn().ok()?;
+
+    // the command holds the write end, which has to be closed to read until cargo exits.
+    drop(command);
+    let mut output = String::new();
+    reader.read_to_string(&mut output).ok()?;
+    let status = child.wait().ok()?;
+
+    let results = parse_test_results(&output);
+    (!results.is_empty() || status.success()).then_some(results)
+}
+
+/// Match the binaries cargo ran with the results of their tests.
+/// A binary that ends without a result, e.g. because it crashed, did not pass.
+fn parse_test_results(output: &str) -> HashMap<Day, bool> {
+    let mut results = HashMap::new();
+    let mut running = None;
+
+    for line in output.lines().map(str::trim) {
+        if let Some(binary) = line.strip_prefix("Running ") {
+            // other binaries, e.g. `src/main.rs`, are run as well and have to be skipped.
+            running = binary
+                .split_once("src/bin/")
+                .and_then(|(_, bin)| bin.split_once(".rs"))
+                .and_then(|(name, _)| name.parse::<Day>().ok());
+            if let Some(day) = running {
+                results.insert(day, false);
+            }
+        } else if let Some(result) = line.strip_prefix("test result: ") {
+            if let Some(day) = running.take() {
+                results.insert(day, result.starts_with("ok"));
+            }
+        }
+    }
+
+    results
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Render the days of an event as a calendar, one column per weekday, followed by the recorded answers.
+pub fn render(year: Year, days: &[DayStatus]) -> Vec<String> {
+    let mut lines = vec![];
+
+    let header: Vec<_> = WEEKDAYS
+        .iter()
+        .map(|weekday| pad(weekday, CELL_WIDTH))
+        .collect();
+    lines.push(format!(
+        "{ANSI_BOLD}{}{ANSI_RESET}",
+        header.join(" ").trim_end()
+    ));
+
+    // 1970-01-01 was a Thursday.
+    let first = days_from_civil(i64::from(year.into_inner()), 12, 1);
+    let offset = usize::try_from((first + 3).rem_euclid(7)).unwrap_or(0);
+
+    let mut cells: Vec<Option<&DayStatus>> = vec![None; offset];
+    cells.extend(days.iter().map(Some));
+
+    for week in cells.chunks(7) {
+        let week: Vec<[String; 3]> = week
+            .iter()
+            .map(|day| day.map(DayStatus::cell).unwrap_or_default())
+            .collect();
+        for row in 0..3 {
+            // weeks without timings take up two lines.
+            if week.iter().all(|cell| cell[row].is_empty()) {
+                continue;
+            }
+            let line: Vec<_> = week
+                .iter()
+                .map(|cell| pad(&cell[row], CELL_WIDTH))
+                .collect();
+            lines.push(line.join(" ").trim_end().to_string());
+        }
+    }
+
+    lines.push(String::new());
+    lines.push(
+        "★ solved  ✗ wrong answers only  ☆ open · S solution  I input  E examples  D description  ✔ tests pass  ✘ tests fail"
+            .into(),
+    );
+
+    let answered: Vec<_> = days
+        .iter()
+        .filter(|day| day.answers.iter().any(Option::is_some))
+        .collect();
+    if !answered.is_empty() {
+        lines.push(String::new());
+        lines.push(format!("{ANSI_BOLD}Answers{ANSI_RESET}"));
+        for day in answered {
+            let [one, two] = &day.answers;
+            lines.push(format!(
+                "{}  {} | {}",
+                day.day,
+                one.as_deref().unwrap_or("-"),
+                two.as_deref().unwrap_or("-")
+            ));
+        }
+    }
+
+    lines
+}
+
+/// Pad text to a width in characters, as the calendar contains symbols that take more than one byte.
+fn pad(text: &str, width: usize) -> String {
+    let padding = width.saturating_sub(text.chars().count());
+    format!("{text}{}", " ".repeat(padding))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_test_results, render, DayStatus, PartStatus};
+    use crate::template::puzzle::Year;
+    use crate::template::Day;
+
+    fn status(day: u8) -> DayStatus {
+        DayStatus {
+            day: Day::new(day).unwrap(),
+            unlocked: true,
+            scaffolded: true,
+            input: true,
+            examples: false,
+            description: true,
+            tests: Some(true),
+            answers: [Some("11".into()), None],
+            timing: Some(74_100.0),
+            parts: [PartStatus::Solved, PartStatus::Attempted],
+        }
+    }
+
+    #[test]
+    fn renders_calendar() {
+        let mut days: Vec<_> = (1..=25).map(status).collect();
+        days[24].unlocked = false;
+        days[1].answers = [None, None];
+        days[1].tests = Some(false);
+
+        let lines = render(Year::new(2024).unwrap(), &days);
+
+        // 2024-12-01 was a Sunday.
+        assert_eq!(
+            lines[0],
+            "\u{1b}[1mMon       Tue       Wed       Thu       Fri       Sat       Sun\u{1b}[0m"
+        );
+        assert_eq!(lines[1], format!("{}01 ★✗", " ".repeat(60)));
+        assert_eq!(lines[2], format!("{}SI·D✔", " ".repeat(60)));
+        assert_eq!(lines[3], format!("{}74.1µs", " ".repeat(60)));
+        assert!(lines[5].starts_with("SI·D✘     SI·D✔"));
+        // day 25 is not unlocked yet, 2024-12-25 was a Wednesday.
+        assert_eq!(lines[13], "23 ★✗     24 ★✗     25");
+        assert!(lines.contains(&"01  11 | -".to_string()));
+        assert!(!lines.iter().any(|line| line.starts_with("02  ")));
+    }
+
+    #[test]
+    fn parses_test_results() {
+        let output = "   Compiling advent_of_code v0.11.0\n     Running unittests src/bin/01.rs (target/debug/deps/01-1)\n\nrunning 2 tests\ntest result: ok. 2 passed; 0 failed\n\n     Running unittests src/main.rs (target/debug/deps/advent_of_code-0)\n\nrunning 0 tests\ntest result: ok. 0 passed; 0 failed\n\n     Running unittests src/bin/02.rs (target/debug/deps/02-2)\n\nrunning 2 tests\ntest result: FAILED. 1 passed; 1 failed\n";
+
+        let results = parse_test_results(output);
+        assert_eq!(results.get(&Day::new(1).unwrap()), Some(&true));
+        assert_eq!(results.get(&Day::new(2).unwrap()), Some(&false));
+    }
+
+    #[test]
+    fn fails_binaries_without_result() {
+        let output = "     Running unittests src/bin/01.rs (target/debug/deps/01-1)\n\nrunning 2 tests\nerror: test failed, to rerun pass `--bin 01`\n\nCaused by:\n  process didn't exit successfully (signal: 11, SIGSEGV)\n     Running unittests src/bin/02.rs (target/debug/deps/02-2)\n\nrunning 2 tests\ntest result: ok. 2 passed; 0 failed\n";
+
+        let results = parse_test_results(output);
+        assert_eq!(results.get(&Day::new(1).unwrap()), Some(&false));
+        assert_eq!(results.get(&Day::new(2).unwrap()), Some(&true));
+    }
+}
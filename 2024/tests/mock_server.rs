@@ -0,0 +1,242 @@
+//! End-to-end tests of the Advent of Code client against the mock server.
+//! Run with `cargo test --features mock-server --test mock_server`.
+#![cfg(feature = "mock-server")]
+
+use std::{
+    env,
+    ffi::OsString,
+    fs,
+    path::PathBuf,
+    sync::{Mutex, MutexGuard, PoisonError},
+    time::Duration,
+};
+
+use advent_of_code::day;
+use advent_of_code::template::aoc_client::{AocClientError, Client, Download, Hint, Outcome};
+use advent_of_code::template::commands::{download, read};
+use advent_of_code::template::mock_server::{MockServer, USER_NAME};
+use advent_of_code::template::puzzle::Year;
+
+static SESSION: &str = "mock-session";
+
+fn fixtures() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/aoc")
+}
+
+fn start(cooldown: Duration) -> MockServer {
+    MockServer::bind("127.0.0.1:0", &fixtures(), SESSION, cooldown).unwrap()
+}
+
+fn client(server: &MockServer, session: &str) -> Client {
+    let year = Year::new(2024).unwrap();
+    Client::new(server.url(), session, "advent_of_code tests", year).with_throttle(Duration::ZERO)
+}
+
+/// Held by tests that change the working directory or the environment, which are shared by the whole process.
+static PROCESS: Mutex<()> = Mutex::new(());
+
+/// A temporary working directory and environment variables, restored and removed when dropped.
+struct Sandbox {
+    dir: PathBuf,
+    cwd: PathBuf,
+    vars: Vec<(&'static str, Option<OsString>)>,
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl Sandbox {
+    fn enter(name: &str, vars: &[(&'static str, &str)]) -> Self {
+        // a failed test must not fail every following one.
+        let lock = PROCESS.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let dir = env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let cwd = env::current_dir().unwrap();
+        env::set_current_dir(&dir).unwrap();
+
+        let vars = vars
+            .iter()
+            .map(|(name, value)| {
+                let previous = env::var_os(name);
+                env::set_var(name, value);
+                (*name, previous)
+            })
+            .collect();
+
+        Sandbox {
+            dir,
+            cwd,
+            vars,
+            _lock: lock,
+        }
+    }
+}
+
+impl Drop for Sandbox {
+    fn drop(&mut self) {
+        for (name, previous) in &self.vars {
+            match previous {
+                Some(value) => env::set_var(name, value),
+                None => env::remove_var(name),
+            }
+        }
+        // leave the directory before removing it.
+        let _ = env::set_current_dir(&self.cwd);
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+#[test]
+fn downloads_and_reads_puzzles() {
+    let server = start(Duration::ZERO);
+
+    // the commands write to `data/` relative to the working directory.
+    let sandbox = Sandbox::enter(
+        "mock-server",
+        &[
+            ("AOC_YEAR", "2024"),
+            ("AOC_BASE_URL", server.url()),
+            ("ADVENT_OF_CODE_SESSION", SESSION),
+            ("AOC_CONTACT", "tests@example.com"),
+            ("AOC_THROTTLE_MS", "0"),
+        ],
+    );
+    let dir = sandbox.dir.clone();
+
+    download::handle(day!(1), false).unwrap();
+    read::handle(day!(1)).unwrap();
+
+    let input = fs::read_to_string(dir.join("data/inputs/01.txt")).unwrap();
+    assert_eq!(
+        input,
+        fs::read_to_string(fixtures().join("01/input.txt")).unwrap()
+    );
+
+    let puzzle = fs::read_to_string(dir.join("data/puzzles/01.md")).unwrap();
+    assert!(puzzle.contains("Day 1: Mock Puzzle"));
+    assert!(matches!(
+        download::handle(day!(2), false),
+        Err(AocClientError::Status(404, _))
+    ));
+    assert!(!puzzle.contains("Part Two"));
+
+    // existing inputs are kept unless forced.
+    let input_path = dir.join("data/inputs/01.txt");
+    fs::write(&input_path, "edited").unwrap();
+    let client = client(&server, SESSION);
+    assert_eq!(client.download(day!(1), false).unwrap(), Download::Skipped);
+    assert_eq!(fs::read_to_string(&input_path).unwrap(), "edited");
+    assert_eq!(
+        client.download(day!(1), true).unwrap(),
+        Download::Fetched { input: true }
+    );
+    assert_eq!(fs::read_to_string(&input_path).unwrap(), input);
+
+    // a missing puzzle is downloaded without replacing the input.
+    fs::write(&input_path, "edited").unwrap();
+    fs::remove_file(dir.join("data/puzzles/01.md")).unwrap();
+    assert_eq!(
+        client.download(day!(1), false).unwrap(),
+        Download::Fetched { input: false }
+    );
+    assert_eq!(fs::read_to_string(&input_path).unwrap(), "edited");
+    assert!(dir.join("data/puzzles/01.md").exists());
+
+    // empty inputs, e.g. created by `scaffold`, are downloaded.
+    fs::write(&input_path, "").unwrap();
+    assert_eq!(
+        client.download(day!(1), false).unwrap(),
+        Download::Fetched { input: true }
+    );
+    assert_eq!(fs::read_to_string(&input_path).unwrap(), input);
+}
+
+#[test]
+fn rejects_invalid_sessions() {
+    let server = start(Duration::ZERO);
+    let client = client(&server, "invalid");
+
+    assert!(matches!(
+        client.input(day!(1)),
+        Err(AocClientError::ExpiredSession)
+    ));
+    assert_eq!(
+        client.submit(day!(1), 1, "11").unwrap().outcome,
+        Outcome::NotLoggedIn
+    );
+    // the puzzle description is public.
+    assert!(client.puzzle(day!(1)).is_ok());
+}
+
+#[test]
+fn submits_answers() {
+    let server = start(Duration::ZERO);
+    let client = client(&server, SESSION);
+
+    let reply = client.submit(day!(1), 1, "12").unwrap();
+    assert!(reply.message.contains("your answer is too high"));
+    assert!(matches!(
+        reply.outcome,
+        Outcome::Incorrect {
+            hint: Some(Hint::TooHigh),
+            wait: 60
+        }
+    ));
+
+    let reply = client.submit(day!(1), 1, "11").unwrap();
+    assert_eq!(reply.outcome, Outcome::Correct);
+    assert!(client.puzzle(day!(1)).unwrap().contains("Part Two"));
+
+    let reply = client.submit(day!(1), 1, "11").unwrap();
+    assert_eq!(reply.outcome, Outcome::AlreadySolved);
+
+    let reply = client.submit(day!(1), 2, "30").unwrap();
+    assert!(matches!(
+        reply.outcome,
+        Outcome::Incorrect {
+            hint: Some(Hint::TooLow),
+            ..
+        }
+    ));
+}
+
+#[test]
+fn rate_limits_wrong_answers() {
+    let server = start(Duration::from_secs(60));
+    let client = client(&server, SESSION);
+
+    let reply = client.submit(day!(1), 1, "wrong").unwrap();
+    assert_eq!(
+        reply.outcome,
+        Outcome::Incorrect {
+            hint: None,
+            wait: 60
+        }
+    );
+
+    let reply = client.submit(day!(1), 1, "11").unwrap();
+    assert!(reply.message.contains("left to wait"));
+    assert!(matches!(reply.outcome, Outcome::RateLimited { wait } if wait > 0 && wait <= 60));
+}
+
+#[test]
+fn serves_leaderboards() {
+    let server = start(Duration::ZERO);
+    let json = client(&server, SESSION).leaderboard("1").unwrap();
+    assert!(json.contains("Mock Owner"));
+
+    assert!(matches!(
+        client(&server, "invalid").leaderboard("1"),
+        Err(AocClientError::ExpiredSession)
+    ));
+}
+
+#[test]
+fn identifies_users() {
+    let server = start(Duration::ZERO);
+    assert_eq!(client(&server, SESSION).user().unwrap(), USER_NAME);
+
+    assert!(matches!(
+        client(&server, "invalid").user(),
+        Err(AocClientError::ExpiredSession)
+    ));
+}
@@ -1,144 +1,253 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use std::fmt::Display;
+
+use advent_of_code::template::cli;
+use advent_of_code::template::commands::{
+    all, answer, auth, completions, download, examples, leaderboard, read, readme, scaffold, solve,
+    stars, status, test_day, time,
+};
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
-#[cfg(feature = "today")]
-use std::process;
+use advent_of_code::template::commands::today;
 
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::cli::{self, CliError, SolutionArgs, APP};
+    use advent_of_code::template::completions::Shell;
+    use advent_of_code::template::puzzle::{self, Year};
+    use advent_of_code::template::{profile, Day};
     use std::process;
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            day: Option<Day>,
+            all: bool,
+            force: bool,
         },
         Read {
             day: Day,
         },
+        Examples {
+            day: Day,
+            pick: Option<String>,
+            overwrite: bool,
+        },
         Scaffold {
             day: Day,
             download: bool,
             overwrite: bool,
+            template: Option<String>,
+            dry_run: bool,
         },
         Solve {
             day: Day,
             release: bool,
             dhat: bool,
-            submit: Option<u8>,
+            solution: SolutionArgs,
+            watch: bool,
+        },
+        TestDay {
+            day: Day,
+            watch: bool,
         },
         All {
             release: bool,
+            all_profiles: bool,
+        },
+        Answer {
+            day: Day,
+            part: u8,
+            answer: String,
+        },
+        Leaderboard {
+            id: Option<String>,
+            file: Option<String>,
+            day: Option<Day>,
         },
+        AuthLogin {
+            token: Option<String>,
+        },
+        AuthStatus,
+        Stars,
+        Status {
+            tests: bool,
+        },
+        Readme,
         Time {
             all: bool,
             day: Option<Day>,
             store: bool,
+            compare: Option<String>,
+            toolchain: Option<String>,
         },
         #[cfg(feature = "today")]
-        Today,
+        Today {
+            wait: bool,
+        },
+        Completions {
+            shell: Shell,
+        },
     }
 
-    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
-        let mut args = pico_args::Arguments::from_env();
+    pub fn parse() -> Result<AppArguments, CliError> {
+        let args = cli::parse_env(&APP);
 
-        let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-            },
-            Some("time") => {
-                let all = args.contains("--all");
-                let store = args.contains("--store");
-
-                AppArguments::Time {
-                    all,
-                    day: args.opt_free_from_str()?,
-                    store,
-                }
+        // the profile and year apply to every command, and are passed on to solutions through the environment.
+        if let Some(profile) = args.get::<String>("--profile")? {
+            if let Err(e) = profile::activate(Some(&profile)) {
+                eprintln!("Error: {e}");
+                process::exit(1);
             }
-            Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+        }
+        if let Some(year) = args.get::<Year>("--year")? {
+            puzzle::activate(year);
+        }
+
+        let app_args = match args.command.as_str() {
+            "all" => AppArguments::All {
+                release: args.flag("--release"),
+                all_profiles: args.flag("--all-profiles"),
+            },
+            "time" => AppArguments::Time {
+                all: args.flag("--all"),
+                day: args.get("day")?,
+                store: args.flag("--store"),
+                compare: args.get("--compare")?,
+                toolchain: args.get("--toolchain")?,
+            },
+            "answer" => AppArguments::Answer {
+                day: args.require("day")?,
+                part: args.require("part")?,
+                answer: args.require("answer")?,
             },
-            Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+            "leaderboard" => AppArguments::Leaderboard {
+                id: args.get("id")?,
+                file: args.get("--file")?,
+                day: args.get("--day")?,
             },
-            Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
-                download: args.contains("--download"),
-                overwrite: args.contains("--overwrite"),
+            "auth login" => AppArguments::AuthLogin {
+                token: args.get("token")?,
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
+            "auth status" => AppArguments::AuthStatus,
+            "stars" => AppArguments::Stars,
+            "status" => AppArguments::Status {
+                tests: !args.flag("--no-tests"),
+            },
+            "readme" => AppArguments::Readme,
+            "download" => AppArguments::Download {
+                day: args.get("day")?,
+                all: args.flag("--all"),
+                force: args.flag("--force"),
+            },
+            "read" => AppArguments::Read {
+                day: args.require("day")?,
+            },
+            "examples" => AppArguments::Examples {
+                day: args.require("day")?,
+                pick: args.get("--pick")?,
+                overwrite: args.flag("--overwrite"),
+            },
+            "scaffold" => AppArguments::Scaffold {
+                day: args.require("day")?,
+                download: args.flag("--download"),
+                overwrite: args.flag("--overwrite"),
+                template: args.get("--template")?,
+                dry_run: args.flag("--dry-run"),
+            },
+            "solve" => AppArguments::Solve {
+                day: args.require("day")?,
+                release: args.flag("--release"),
+                dhat: args.flag("--dhat"),
+                solution: SolutionArgs::from_matches(&args)?,
+                watch: args.flag("--watch"),
+            },
+            "test-day" => AppArguments::TestDay {
+                day: args.require("day")?,
+                watch: args.flag("--watch"),
             },
             #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
-            Some(x) => {
-                eprintln!("Unknown command: {x}");
-                process::exit(1);
-            }
-            None => {
-                eprintln!("No command specified.");
-                process::exit(1);
-            }
+            "today" => AppArguments::Today {
+                wait: args.flag("--wait"),
+            },
+            "completions" => AppArguments::Completions {
+                shell: args.require("shell")?,
+            },
+            command => unreachable!("command `{command}` is described but not handled"),
         };
 
-        let remaining = args.finish();
-        if !remaining.is_empty() {
-            eprintln!("Warning: unknown argument(s): {remaining:?}.");
-        }
-
         Ok(app_args)
     }
 }
 
+/// Report the error of a command, e.g. a failed request to Advent of Code, and exit.
+fn exit_on_error<E: Display>(result: Result<(), E>) {
+    if let Err(e) = result {
+        eprintln!("Error: {e}");
+        std::process::exit(1);
+    }
+}
+
 fn main() {
     match parse() {
-        Err(err) => {
-            eprintln!("Error: {err}");
-            std::process::exit(1);
-        }
+        Err(err) => cli::exit_with(&err),
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+            AppArguments::All {
+                release,
+                all_profiles,
+            } => all::handle(release, all_profiles),
+            AppArguments::Time {
+                day,
+                all,
+                store,
+                compare,
+                toolchain,
+            } => time::handle(day, all, store, compare, toolchain),
+            AppArguments::Answer { day, part, answer } => answer::handle(day, part, &answer),
+            AppArguments::Leaderboard { id, file, day } => leaderboard::handle(id, file, day),
+            AppArguments::AuthLogin { token } => auth::login(token),
+            AppArguments::AuthStatus => exit_on_error(auth::status()),
+            AppArguments::Stars => stars::handle(),
+            AppArguments::Status { tests } => status::handle(tests),
+            AppArguments::Readme => readme::handle(),
+            AppArguments::Download { day, all, force } => match (day, all) {
+                (Some(day), false) => exit_on_error(download::handle(day, force)),
+                (None, true) => exit_on_error(download::handle_all(force)),
+                _ => {
+                    eprintln!("Expected either a day or --all. Format: cargo download 1");
+                    std::process::exit(1);
+                }
+            },
+            AppArguments::Read { day } => exit_on_error(read::handle(day)),
+            AppArguments::Examples {
+                day,
+                pick,
+                overwrite,
+            } => examples::handle(day, pick, overwrite),
             AppArguments::Scaffold {
                 day,
                 download,
                 overwrite,
+                template,
+                dry_run,
             } => {
-                scaffold::handle(day, overwrite);
-                if download {
-                    download::handle(day);
-                }
+                exit_on_error(scaffold::handle(
+                    day,
+                    overwrite,
+                    template.as_deref(),
+                    dry_run,
+                    download,
+                ));
             }
             AppArguments::Solve {
                 day,
                 release,
                 dhat,
-                submit,
-            } => solve::handle(day, release, dhat, submit),
+                solution,
+                watch,
+            } => solve::handle(day, release, dhat, &solution, watch),
+            AppArguments::TestDay { day, watch } => test_day::handle(day, watch),
             #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
-                    }
-                    None => {
-                        eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
-                        );
-                        process::exit(1)
-                    }
-                };
-            }
+            AppArguments::Today { wait } => exit_on_error(today::handle(wait)),
+            AppArguments::Completions { shell } => completions::handle(shell),
         },
     };
 }
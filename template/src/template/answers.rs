@@ -0,0 +1,82 @@
+/// Module that records accepted answers in `data/answers`, one file per part (e.g. `01-2.txt`).
+/// The recorded answers of the default profile are the source of the stars table in the readme.
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+use crate::template::{all_days, profile, Day};
+
+#[must_use]
+pub fn get_answer_path(day: Day, part: u8, profile: Option<&str>) -> String {
+    profile::data_path("answers", &format!("{day}-{part}.txt"), profile)
+}
+
+/// Read the accepted answer of a part, if it was recorded.
+pub fn read(day: Day, part: u8, profile: Option<&str>) -> Option<String> {
+    let answer = fs::read_to_string(get_answer_path(day, part, profile)).ok()?;
+    let answer = answer.trim();
+    (!answer.is_empty()).then(|| answer.to_string())
+}
+
+/// Record the accepted answer of a part.
+pub fn store(day: Day, part: u8, answer: &str, profile: Option<&str>) -> io::Result<()> {
+    let path = get_answer_path(day, part, profile);
+    if let Some(dir) = Path::new(&path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, format!("{}\n", answer.trim()))
+}
+
+/// Represents the solved parts of every day.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Progress {
+    pub days: BTreeMap<Day, [bool; 2]>,
+}
+
+impl Progress {
+    /// Collect progress from the recorded answers of the default profile.
+    pub fn read() -> Self {
+        let days = all_days()
+            .map(|day| (day, [1, 2].map(|part| read(day, part, None).is_some())))
+            .filter(|(_, parts)| parts.iter().any(|solved| *solved))
+            .collect();
+
+        Progress { days }
+    }
+
+    pub fn stars(&self) -> usize {
+        self.days
+            .values()
+            .map(|parts| parts.iter().filter(|solved| **solved).count())
+            .sum()
+    }
+
+    /// Number of days with both parts solved.
+    pub fn days_solved(&self) -> usize {
+        self.days
+            .values()
+            .filter(|parts| parts.iter().all(|solved| *solved))
+            .count()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::Progress;
+    use crate::day;
+
+    #[test]
+    fn counts_stars_and_solved_days() {
+        let progress = Progress {
+            days: BTreeMap::from([
+                (day!(1), [true, true]),
+                (day!(2), [true, false]),
+                (day!(3), [true, true]),
+            ]),
+        };
+        assert_eq!(progress.stars(), 5);
+        assert_eq!(progress.days_solved(), 2);
+    }
+}
@@ -0,0 +1,202 @@
+/// Module that writes small, self-contained SVG badges to the `assets` directory of the repository.
+/// Badges are generated locally, so they can be referenced from the readme without a badge service.
+use std::{fs, io};
+
+use crate::template::answers::Progress;
+use crate::template::layout::Layout;
+use crate::template::timings::{format_nanos, Timings};
+
+static COLOR_LABEL: &str = "#555";
+static COLOR_STARS: &str = "#dfb317";
+static COLOR_DAYS: &str = "#007ec6";
+static COLOR_PASS: &str = "#4c1";
+static COLOR_FAIL: &str = "#e05d44";
+
+/// Approximate width of a character in the badge font, in pixels.
+const CHAR_WIDTH: usize = 7;
+const PADDING: usize = 10;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Badge {
+    /// File name without extension, e.g. `stars`.
+    pub name: &'static str,
+    pub label: String,
+    pub value: String,
+    pub color: &'static str,
+}
+
+impl Badge {
+    pub fn stars(progress: &Progress) -> Self {
+        Badge {
+            name: "stars",
+            label: "stars".into(),
+            value: progress.stars().to_string(),
+            color: COLOR_STARS,
+        }
+    }
+
+    pub fn days(progress: &Progress) -> Self {
+        Badge {
+            name: "days",
+            label: "days solved".into(),
+            value: progress.days_solved().to_string(),
+            color: COLOR_DAYS,
+        }
+    }
+
+    /// Total runtime of all stored timings. If a budget is given, the badge shows whether it is met.
+    pub fn runtime(timings: &Timings, budget: Option<f64>) -> Self {
+        let total = timings.total_millis() * 1_000_000_f64;
+
+        let color = match budget {
+            Some(budget) if total > budget => COLOR_FAIL,
+            Some(_) => COLOR_PASS,
+            None => COLOR_DAYS,
+        };
+
+        Badge {
+            name: "runtime",
+            label: "runtime".into(),
+            value: format_nanos(total),
+            color,
+        }
+    }
+
+    pub fn to_svg(&self) -> String {
+        let label_width = text_width(&self.label);
+        let value_width = text_width(&self.value);
+        let width = label_width + value_width;
+
+        let label = escape(&self.label);
+        let value = escape(&self.value);
+
+        // text is rendered at 10x scale, so positions and lengths are multiplied as well.
+        let label_x = label_width * 5;
+        let value_x = label_width * 10 + value_width * 5;
+        let label_length = (label_width - PADDING) * 10;
+        let value_length = (value_width - PADDING) * 10;
+
+        [
+            format!(r##"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="20" role="img" aria-label="{label}: {value}">"##),
+            format!("<title>{label}: {value}</title>"),
+            r##"<linearGradient id="s" x2="0" y2="100%"><stop offset="0" stop-color="#bbb" stop-opacity=".1"/><stop offset="1" stop-opacity=".1"/></linearGradient>"##.into(),
+            format!(r##"<clipPath id="r"><rect width="{width}" height="20" rx="3" fill="#fff"/></clipPath>"##),
+            format!(r##"<g clip-path="url(#r)"><rect width="{label_width}" height="20" fill="{COLOR_LABEL}"/><rect x="{label_width}" width="{value_width}" height="20" fill="{}"/><rect width="{width}" height="20" fill="url(#s)"/></g>"##, self.color),
+            r##"<g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size="110">"##.into(),
+            format!(r##"<text x="{label_x}" y="140" transform="scale(.1)" textLength="{label_length}">{label}</text>"##),
+            format!(r##"<text x="{value_x}" y="140" transform="scale(.1)" textLength="{value_length}">{value}</text>"##),
+            "</g>".into(),
+            "</svg>".into(),
+        ]
+        .join("\n")
+            + "\n"
+    }
+
+    /// Write the badge to the assets directory. The file is only written if its content changed.
+    /// Returns whether the file was written.
+    pub fn store(&self, layout: &Layout) -> io::Result<bool> {
+        let path = layout.badge_path(self.name);
+        let svg = self.to_svg();
+
+        if fs::read_to_string(&path).is_ok_and(|existing| existing == svg) {
+            return Ok(false);
+        }
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        fs::write(path, svg)?;
+        Ok(true)
+    }
+}
+
+/// Write the `stars` and `days` badges.
+pub fn store_progress(progress: &Progress) -> io::Result<()> {
+    let layout = Layout::discover()?;
+    Badge::stars(progress).store(&layout)?;
+    Badge::days(progress).store(&layout)?;
+    Ok(())
+}
+
+/// Write the `runtime` badge.
+pub fn store_timings(timings: &Timings, budget: Option<f64>) -> io::Result<()> {
+    let layout = Layout::discover()?;
+    Badge::runtime(timings, budget).store(&layout)?;
+    Ok(())
+}
+
+fn text_width(text: &str) -> usize {
+    text.chars().count() * CHAR_WIDTH + PADDING
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{Badge, COLOR_FAIL, COLOR_PASS};
+    use crate::{
+        day,
+        template::{
+            answers::Progress,
+            timings::{Timing, Timings},
+        },
+    };
+
+    fn get_mock_timings() -> Timings {
+        Timings {
+            data: vec![Timing {
+                day: day!(1),
+                part_1: Some("10ms".into()),
+                part_2: Some("20ms".into()),
+                total_nanos: 3e+7,
+                part_1_stats: None,
+                part_2_stats: None,
+            }],
+        }
+    }
+
+    #[test]
+    fn renders_progress_badges() {
+        let progress = Progress {
+            days: BTreeMap::from([(day!(1), [true, true]), (day!(2), [true, false])]),
+        };
+
+        let stars = Badge::stars(&progress).to_svg();
+        assert!(stars.contains("<title>stars: 3</title>"));
+        assert!(stars.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="62""#));
+
+        let days = Badge::days(&progress).to_svg();
+        assert!(days.contains("<title>days solved: 1</title>"));
+    }
+
+    #[test]
+    fn colors_runtime_by_budget() {
+        let timings = get_mock_timings();
+        assert_eq!(Badge::runtime(&timings, None).value, "30.0ms");
+        assert_eq!(Badge::runtime(&timings, Some(4e+7)).color, COLOR_PASS);
+        assert_eq!(Badge::runtime(&timings, Some(2e+7)).color, COLOR_FAIL);
+    }
+
+    #[test]
+    fn escapes_text() {
+        let badge = Badge {
+            name: "test",
+            label: "a<b".into(),
+            value: "\"&\"".into(),
+            color: COLOR_PASS,
+        };
+        assert!(badge
+            .to_svg()
+            .contains("<title>a&lt;b: &quot;&amp;&quot;</title>"));
+    }
+}
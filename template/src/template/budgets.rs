@@ -0,0 +1,254 @@
+/// Module that checks benchmark timings against the time budgets configured in `aoc.json`.
+use std::{collections::HashMap, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::run_multi::child_commands::parse_duration;
+use crate::template::timings::{Timing, Timings};
+use crate::template::{Day, ANSI_BOLD, ANSI_GREEN, ANSI_ITALIC, ANSI_RED, ANSI_RESET};
+
+/// Number of days listed in the ranking of the slowest days.
+const RANKED_DAYS: usize = 5;
+
+/// Represents time budgets for the whole year and for individual days.
+/// All budgets are stored in nanoseconds.
+#[derive(Clone, Debug, Default)]
+pub struct Budgets {
+    /// Budget for the sum of all days.
+    pub total: Option<f64>,
+    /// Default budget for a single day.
+    pub day: Option<f64>,
+    /// Budgets for specific days, overriding the default.
+    pub days: HashMap<Day, f64>,
+    /// Whether `cargo time` should fail when a budget is exceeded.
+    pub fail: bool,
+}
+
+impl Budgets {
+    /// Returns the budget that applies to a day, if any.
+    pub fn for_day(&self, day: Day) -> Option<f64> {
+        self.days.get(&day).copied().or(self.day)
+    }
+
+    pub fn is_day_over_budget(&self, timing: &Timing) -> bool {
+        self.for_day(timing.day)
+            .is_some_and(|budget| timing.total_nanos > budget)
+    }
+
+    pub fn is_total_over_budget(&self, timings: &Timings) -> bool {
+        self.total
+            .is_some_and(|budget| timings.total_millis() * 1_000_000_f64 > budget)
+    }
+
+    /// Returns `true` if the total or any day exceeds its budget.
+    pub fn is_exceeded(&self, timings: &Timings) -> bool {
+        self.is_total_over_budget(timings)
+            || timings.data.iter().any(|t| self.is_day_over_budget(t))
+    }
+
+    /// Returns up to `n` timings, ordered by their contribution to the total.
+    pub fn rank(timings: &Timings, n: usize) -> Vec<&Timing> {
+        let mut ranked: Vec<&Timing> = timings.data.iter().collect();
+        ranked.sort_unstable_by(|a, b| b.total_nanos.total_cmp(&a.total_nanos));
+        ranked.truncate(n);
+        ranked
+    }
+
+    /// Print consumption of every budget and the days contributing most to the total.
+    pub fn print_report(&self, timings: &Timings) {
+        let total_nanos = timings.total_millis() * 1_000_000_f64;
+
+        println!("\n{ANSI_BOLD}Budgets{ANSI_RESET}");
+        println!("-------");
+
+        if let Some(budget) = self.total {
+            println!(
+                "Total: {}",
+                format_consumption(total_nanos, budget, self.is_total_over_budget(timings))
+            );
+        }
+
+        for timing in &timings.data {
+            if let Some(budget) = self.for_day(timing.day) {
+                println!(
+                    "Day {}: {}",
+                    timing.day,
+                    format_consumption(timing.total_nanos, budget, self.is_day_over_budget(timing))
+                );
+            }
+        }
+
+        if total_nanos > 0_f64 {
+            println!("\n{ANSI_BOLD}Slowest days{ANSI_RESET}");
+            for (i, timing) in Self::rank(timings, RANKED_DAYS).iter().enumerate() {
+                println!(
+                    "{}. Day {}: {:.2}ms {ANSI_ITALIC}({:.1}% of total){ANSI_RESET}",
+                    i + 1,
+                    timing.day,
+                    timing.total_nanos / 1_000_000_f64,
+                    timing.total_nanos / total_nanos * 100_f64
+                );
+            }
+        }
+    }
+}
+
+fn format_consumption(nanos: f64, budget: f64, is_over_budget: bool) -> String {
+    let color = if is_over_budget { ANSI_RED } else { ANSI_GREEN };
+    format!(
+        "{color}{:.2}ms{ANSI_RESET} / {:.2}ms ({:.1}%)",
+        nanos / 1_000_000_f64,
+        budget / 1_000_000_f64,
+        nanos / budget * 100_f64
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn parse_budget(value: &JsonValue, key: &str) -> Result<f64, String> {
+    value
+        .get::<String>()
+        .and_then(|s| parse_duration(s.trim()))
+        .ok_or(format!(
+            "Expected budgets.{key} to be a duration such as \"40ms\"."
+        ))
+}
+
+impl TryFrom<&JsonValue> for Budgets {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected budgets to be a JSON object.")?;
+
+        let total = json
+            .get("total")
+            .map(|v| parse_budget(v, "total"))
+            .transpose()?;
+
+        let day = json
+            .get("day")
+            .map(|v| parse_budget(v, "day"))
+            .transpose()?;
+
+        let days = match json.get("days") {
+            Some(days) => days
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or("Expected budgets.days to be a JSON object.")?
+                .iter()
+                .map(|(key, v)| {
+                    let day = Day::from_str(key)
+                        .map_err(|_| format!("Expected budgets.days.{key} to be a day."))?;
+                    Ok((day, parse_budget(v, &format!("days.{key}"))?))
+                })
+                .collect::<Result<_, String>>()?,
+            None => HashMap::new(),
+        };
+
+        let fail = json
+            .get("fail")
+            .map(|v| v.get::<bool>().copied())
+            .unwrap_or(Some(false))
+            .ok_or("Expected budgets.fail to be a boolean.")?;
+
+        Ok(Budgets {
+            total,
+            day,
+            days,
+            fail,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::str::FromStr;
+    use tinyjson::JsonValue;
+
+    use super::Budgets;
+    use crate::{
+        day,
+        template::timings::{Timing, Timings},
+    };
+
+    fn get_mock_timings() -> Timings {
+        Timings {
+            data: vec![
+                Timing {
+                    day: day!(1),
+                    part_1: Some("10ms".into()),
+                    part_2: Some("20ms".into()),
+                    total_nanos: 3e+7,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                },
+                Timing {
+                    day: day!(2),
+                    part_1: Some("300ms".into()),
+                    part_2: Some("400ms".into()),
+                    total_nanos: 7e+8,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                },
+                Timing {
+                    day: day!(4),
+                    part_1: Some("40ms".into()),
+                    part_2: None,
+                    total_nanos: 4e+7,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                },
+            ],
+        }
+    }
+
+    fn parse(json: &str) -> Result<Budgets, String> {
+        Budgets::try_from(&JsonValue::from_str(json).unwrap())
+    }
+
+    #[test]
+    fn parses_budgets() {
+        let budgets =
+            parse(r#"{ "total": "1s", "day": "50ms", "days": { "2": "1s" }, "fail": true }"#)
+                .unwrap();
+        assert_eq!(budgets.total, Some(1e+9));
+        assert_eq!(budgets.for_day(day!(1)), Some(5e+7));
+        assert_eq!(budgets.for_day(day!(2)), Some(1e+9));
+        assert!(budgets.fail);
+    }
+
+    #[test]
+    fn rejects_invalid_durations() {
+        assert!(parse(r#"{ "total": 1000 }"#).is_err());
+        assert!(parse(r#"{ "days": { "26": "1s" } }"#).is_err());
+    }
+
+    #[test]
+    fn detects_exceeded_day_budgets() {
+        let budgets = parse(r#"{ "day": "50ms" }"#).unwrap();
+        let timings = get_mock_timings();
+        assert!(!budgets.is_day_over_budget(&timings.data[0]));
+        assert!(budgets.is_day_over_budget(&timings.data[1]));
+        assert!(budgets.is_exceeded(&timings));
+    }
+
+    #[test]
+    fn detects_exceeded_total_budget() {
+        let timings = get_mock_timings();
+        assert!(!parse(r#"{ "total": "1s" }"#).unwrap().is_exceeded(&timings));
+        assert!(parse(r#"{ "total": "500ms" }"#)
+            .unwrap()
+            .is_exceeded(&timings));
+    }
+
+    #[test]
+    fn ranks_slowest_days() {
+        let timings = get_mock_timings();
+        let ranked = Budgets::rank(&timings, 2);
+        assert_eq!(ranked.len(), 2);
+        assert_eq!(ranked[0].day, day!(2));
+        assert_eq!(ranked[1].day, day!(4));
+    }
+}
@@ -0,0 +1,434 @@
+/// Module that renders benchmark timings as a table with configurable columns.
+/// The same configuration is used for the readme and for the summary printed by `cargo time`.
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::layout::Layout;
+use crate::template::timings::{format_nanos, PartStats, Timing, Timings};
+use crate::template::Day;
+
+/// Relative change of the mean below which the trend of a part is considered flat.
+const TREND_THRESHOLD: f64 = 0.05;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Column {
+    Day,
+    Title,
+    Part1,
+    Part2,
+    Total,
+    Median,
+    Min,
+    StdDev,
+    Samples,
+    Parse,
+    Allocations,
+    Trend,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum SortOrder {
+    #[default]
+    Day,
+    /// Slowest days first.
+    Time,
+}
+
+/// The `benchmarks` section of `aoc.json`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BenchmarkTable {
+    pub columns: Vec<Column>,
+    pub sort: SortOrder,
+}
+
+impl Default for BenchmarkTable {
+    fn default() -> Self {
+        BenchmarkTable {
+            columns: vec![Column::Day, Column::Part1, Column::Part2],
+            sort: SortOrder::Day,
+        }
+    }
+}
+
+/// Target format of a rendered cell.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Style {
+    Markdown,
+    Terminal,
+}
+
+impl Column {
+    fn header(self) -> &'static str {
+        match self {
+            Column::Day => "Day",
+            Column::Title => "Title",
+            Column::Part1 => "Part 1",
+            Column::Part2 => "Part 2",
+            Column::Total => "Total",
+            Column::Median => "Median",
+            Column::Min => "Min",
+            Column::StdDev => "Std. dev.",
+            Column::Samples => "Samples",
+            Column::Parse => "Parse",
+            Column::Allocations => "Allocations",
+            Column::Trend => "Trend",
+        }
+    }
+
+    /// Renders one value for each part, e.g. `` `1.2ms` / `3.4ms` ``.
+    fn per_part<T: Display>(
+        timing: &Timing,
+        style: Style,
+        value: impl Fn(&PartStats) -> Option<T>,
+    ) -> String {
+        [1, 2]
+            .map(|part| {
+                let value = timing
+                    .stats(part)
+                    .and_then(&value)
+                    .map_or_else(|| "-".to_string(), |v| v.to_string());
+                code(&value, style)
+            })
+            .join(" / ")
+    }
+
+    fn cell(
+        self,
+        timing: &Timing,
+        title: Option<&str>,
+        layout: Option<&Layout>,
+        style: Style,
+    ) -> String {
+        match self {
+            Column::Day => match (style, layout) {
+                (Style::Markdown, Some(layout)) => format!(
+                    "[Day {}]({})",
+                    timing.day.into_inner(),
+                    layout.link_to_bin(timing.day)
+                ),
+                (Style::Markdown, None) => format!("Day {}", timing.day.into_inner()),
+                (Style::Terminal, _) => format!("Day {}", timing.day),
+            },
+            Column::Title => match style {
+                Style::Markdown => title.unwrap_or("-").replace('|', "\\|"),
+                Style::Terminal => title.unwrap_or("-").to_string(),
+            },
+            Column::Part1 => code(timing.part_1.as_deref().unwrap_or("-"), style),
+            Column::Part2 => code(timing.part_2.as_deref().unwrap_or("-"), style),
+            Column::Total => code(&format_nanos(timing.total_nanos), style),
+            Column::Median => Self::per_part(timing, style, |s| Some(format_nanos(s.median))),
+            Column::Min => Self::per_part(timing, style, |s| Some(format_nanos(s.min))),
+            Column::StdDev => Self::per_part(timing, style, |s| Some(format_nanos(s.stddev))),
+            Column::Samples => Self::per_part(timing, style, |s| Some(s.samples)),
+            Column::Parse => Self::per_part(timing, style, |s| s.parse.map(format_nanos)),
+            Column::Allocations => Self::per_part(timing, style, |s| s.allocations),
+            Column::Trend => [1, 2].map(|part| trend(timing.stats(part))).join(" / "),
+        }
+    }
+}
+
+fn code(value: &str, style: Style) -> String {
+    match style {
+        Style::Markdown => format!("`{value}`"),
+        Style::Terminal => value.to_string(),
+    }
+}
+
+/// Returns an arrow showing how the mean of a part changed since the previous run.
+/// `↓` means faster, `↑` means slower.
+fn trend(stats: Option<&PartStats>) -> &'static str {
+    let Some((mean, previous)) = stats.and_then(|s| Some((s.mean, s.previous?))) else {
+        return "-";
+    };
+
+    let change = (mean - previous) / previous;
+
+    if change > TREND_THRESHOLD {
+        "↑"
+    } else if change < -TREND_THRESHOLD {
+        "↓"
+    } else {
+        "→"
+    }
+}
+
+impl BenchmarkTable {
+    pub fn has_column(&self, column: Column) -> bool {
+        self.columns.contains(&column)
+    }
+
+    fn sorted<'a>(&self, timings: &'a Timings) -> Vec<&'a Timing> {
+        let mut rows: Vec<&Timing> = timings.data.iter().collect();
+        match self.sort {
+            SortOrder::Day => rows.sort_unstable_by_key(|t| t.day),
+            SortOrder::Time => {
+                rows.sort_unstable_by(|a, b| b.total_nanos.total_cmp(&a.total_nanos));
+            }
+        }
+        rows
+    }
+
+    fn rows(
+        &self,
+        timings: &Timings,
+        titles: &impl Fn(Day) -> Option<String>,
+        layout: Option<&Layout>,
+        style: Style,
+    ) -> Vec<Vec<String>> {
+        self.sorted(timings)
+            .into_iter()
+            .map(|timing| {
+                let title = if self.has_column(Column::Title) {
+                    titles(timing.day)
+                } else {
+                    None
+                };
+
+                self.columns
+                    .iter()
+                    .map(|column| column.cell(timing, title.as_deref(), layout, style))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Render the table as Markdown lines.
+    pub fn to_markdown(
+        &self,
+        timings: &Timings,
+        titles: &impl Fn(Day) -> Option<String>,
+        layout: &Layout,
+    ) -> Vec<String> {
+        let mut lines = vec![
+            format!(
+                "| {} |",
+                self.columns
+                    .iter()
+                    .map(|c| c.header())
+                    .collect::<Vec<_>>()
+                    .join(" | ")
+            ),
+            // NOTE: the trailing double space matches tables written by earlier versions.
+            format!(
+                "|{}  |",
+                vec![" :---: "; self.columns.len()].join("|").trim_end()
+            ),
+        ];
+
+        for row in self.rows(timings, titles, Some(layout), Style::Markdown) {
+            lines.push(format!("| {} |", row.join(" | ")));
+        }
+
+        lines
+    }
+
+    /// Render the table as aligned plain-text lines.
+    pub fn to_terminal(
+        &self,
+        timings: &Timings,
+        titles: &impl Fn(Day) -> Option<String>,
+    ) -> Vec<String> {
+        let header: Vec<String> = self.columns.iter().map(|c| c.header().into()).collect();
+        let rows = self.rows(timings, titles, None, Style::Terminal);
+
+        let widths: Vec<usize> = (0..self.columns.len())
+            .map(|i| {
+                std::iter::once(&header)
+                    .chain(rows.iter())
+                    .map(|row| row[i].chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        std::iter::once(header)
+            .chain(rows)
+            .map(|row| {
+                row.iter()
+                    .zip(&widths)
+                    .map(|(cell, width)| format!("{cell:<width$}"))
+                    .collect::<Vec<_>>()
+                    .join("  ")
+                    .trim_end()
+                    .to_string()
+            })
+            .collect()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Column {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "day" => Ok(Column::Day),
+            "title" => Ok(Column::Title),
+            "part_1" => Ok(Column::Part1),
+            "part_2" => Ok(Column::Part2),
+            "total" => Ok(Column::Total),
+            "median" => Ok(Column::Median),
+            "min" => Ok(Column::Min),
+            "stddev" => Ok(Column::StdDev),
+            "samples" => Ok(Column::Samples),
+            "parse" => Ok(Column::Parse),
+            "allocations" => Ok(Column::Allocations),
+            "trend" => Ok(Column::Trend),
+            _ => Err(format!("unknown benchmark column `{s}`.")),
+        }
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchmarkTable {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected benchmarks to be a JSON object.")?;
+
+        let columns = match json.get("columns") {
+            Some(columns) => columns
+                .get::<Vec<JsonValue>>()
+                .ok_or("Expected benchmarks.columns to be an array.")?
+                .iter()
+                .map(|v| {
+                    v.get::<String>()
+                        .ok_or("Expected benchmarks.columns to contain strings.".to_string())
+                        .and_then(|s| Column::from_str(s))
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            None => BenchmarkTable::default().columns,
+        };
+
+        if columns.is_empty() {
+            return Err("Expected benchmarks.columns to contain at least one column.".into());
+        }
+
+        let sort = match json
+            .get("sort")
+            .map(|v| v.get::<String>().map(String::as_str))
+        {
+            None | Some(Some("day")) => SortOrder::Day,
+            Some(Some("time")) => SortOrder::Time,
+            _ => return Err("Expected benchmarks.sort to be \"day\" or \"time\".".into()),
+        };
+
+        Ok(BenchmarkTable { columns, sort })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{path::Path, str::FromStr};
+    use tinyjson::JsonValue;
+
+    use super::{BenchmarkTable, Column, SortOrder};
+    use crate::{
+        day,
+        template::{
+            layout::Layout,
+            timings::{PartStats, Timing, Timings},
+        },
+    };
+
+    fn get_mock_timings() -> Timings {
+        Timings {
+            data: vec![
+                Timing {
+                    day: day!(1),
+                    part_1: Some("10.0ms".into()),
+                    part_2: None,
+                    total_nanos: 1e+7,
+                    part_1_stats: Some(PartStats {
+                        mean: 1e+7,
+                        median: 9e+6,
+                        min: 8e+6,
+                        stddev: 1e+6,
+                        samples: 100,
+                        parse: None,
+                        allocations: Some(3),
+                        previous: Some(2e+7),
+                    }),
+                    part_2_stats: None,
+                },
+                Timing {
+                    day: day!(2),
+                    part_1: Some("30.0ms".into()),
+                    part_2: Some("40.0ms".into()),
+                    total_nanos: 7e+7,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                },
+            ],
+        }
+    }
+
+    fn get_mock_layout() -> Layout {
+        Layout::new(Path::new("/repo"), Path::new("/repo"), None)
+    }
+
+    fn parse(json: &str) -> Result<BenchmarkTable, String> {
+        BenchmarkTable::try_from(&JsonValue::from_str(json).unwrap())
+    }
+
+    #[test]
+    fn renders_default_columns() {
+        let lines = BenchmarkTable::default().to_markdown(
+            &get_mock_timings(),
+            &|_| None,
+            &get_mock_layout(),
+        );
+        assert_eq!(
+            lines,
+            [
+                "| Day | Part 1 | Part 2 |",
+                "| :---: | :---: | :---:  |",
+                "| [Day 1](./src/bin/01.rs) | `10.0ms` | `-` |",
+                "| [Day 2](./src/bin/02.rs) | `30.0ms` | `40.0ms` |",
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_configured_columns() {
+        let table = parse(
+            r#"{ "columns": ["day", "title", "median", "allocations", "trend"], "sort": "time" }"#,
+        )
+        .unwrap();
+        let lines = table.to_markdown(
+            &get_mock_timings(),
+            &|day| (day == day!(1)).then(|| "Historian Hysteria".to_string()),
+            &get_mock_layout(),
+        );
+        assert_eq!(lines[0], "| Day | Title | Median | Allocations | Trend |");
+        assert_eq!(
+            lines[2],
+            "| [Day 2](./src/bin/02.rs) | - | `-` / `-` | `-` / `-` | - / - |"
+        );
+        assert_eq!(
+            lines[3],
+            "| [Day 1](./src/bin/01.rs) | Historian Hysteria | `9.0ms` / `-` | `3` / `-` | ↓ / - |"
+        );
+    }
+
+    #[test]
+    fn renders_terminal_rows() {
+        let table = parse(r#"{ "columns": ["day", "total"] }"#).unwrap();
+        let lines = table.to_terminal(&get_mock_timings(), &|_| None);
+        assert_eq!(lines, ["Day     Total", "Day 01  10.0ms", "Day 02  70.0ms"]);
+    }
+
+    #[test]
+    fn parses_config() {
+        let table = parse(r#"{ "columns": ["day", "stddev"] }"#).unwrap();
+        assert_eq!(table.columns, vec![Column::Day, Column::StdDev]);
+        assert_eq!(table.sort, SortOrder::Day);
+        assert!(parse(r#"{ "columns": ["day", "nope"] }"#).is_err());
+        assert!(parse(r#"{ "columns": [] }"#).is_err());
+        assert!(parse(r#"{ "sort": "alphabetical" }"#).is_err());
+    }
+}
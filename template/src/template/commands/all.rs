@@ -1,5 +1,77 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::{fs, iter, path::Path, process};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::run_multi::{child_commands, get_path_for_bin, run_multi};
+use crate::template::{all_days, answers, profile, ANSI_BOLD, ANSI_RESET};
+
+pub fn handle(is_release: bool, all_profiles: bool) {
+    if all_profiles {
+        check_profiles(is_release);
+    } else {
+        run_multi(&all_days().collect(), is_release, false, false);
+    }
+}
+
+/// Run every day against the inputs of every profile and report answers that differ from the recorded ones.
+fn check_profiles(is_release: bool) {
+    let profiles: Vec<Option<String>> = iter::once(None)
+        .chain(profile::stored().into_iter().map(Some))
+        .collect();
+
+    let mut checked = 0;
+    let mut disagreements = vec![];
+
+    for profile in &profiles {
+        let name = profile.as_deref().unwrap_or("default");
+
+        if let Err(e) = profile::activate(profile.as_deref()) {
+            eprintln!("Skipping profile {name}: {e}");
+            continue;
+        }
+
+        println!("{ANSI_BOLD}Profile {name}{ANSI_RESET}");
+        println!("======");
+
+        for day in all_days() {
+            let input = profile::data_path("inputs", &format!("{day}.txt"), profile.as_deref());
+            let has_input = fs::metadata(&input).is_ok_and(|m| m.len() > 0);
+
+            if !has_input || !Path::new(&get_path_for_bin(day)).exists() {
+                continue;
+            }
+
+            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+            println!("------");
+
+            let output = child_commands::run_solution(day, false, is_release, false).unwrap();
+
+            for (part, answer) in (1..=2).zip(child_commands::parse_answers(&output)) {
+                let Some(recorded) = answers::read(day, part, profile.as_deref()) else {
+                    continue;
+                };
+
+                checked += 1;
+                match answer {
+                    Some(answer) if answer == recorded => {}
+                    answer => disagreements.push(format!(
+                        "{name}, day {day}, part {part}: expected `{recorded}`, got `{}`.",
+                        answer.as_deref().unwrap_or("nothing")
+                    )),
+                }
+            }
+        }
+
+        println!();
+    }
+
+    println!(
+        "{ANSI_BOLD}Checked {checked} recorded answers of {} profiles.{ANSI_RESET}",
+        profiles.len()
+    );
+
+    if !disagreements.is_empty() {
+        for disagreement in &disagreements {
+            eprintln!("{disagreement}");
+        }
+        process::exit(1);
+    }
 }
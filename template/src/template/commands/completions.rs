@@ -0,0 +1,7 @@
+use crate::template::active_length;
+use crate::template::cli::APP;
+use crate::template::completions::{generate, Shell};
+
+pub fn handle(shell: Shell) {
+    print!("{}", generate(shell, &APP, active_length()));
+}
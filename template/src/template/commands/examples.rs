@@ -0,0 +1,120 @@
+use std::{fs, path::Path, process};
+
+use crate::template::examples::{fill_tests, get_example_paths, Examples};
+use crate::template::Day;
+
+/// Number of lines shown per block when listing candidates.
+const PREVIEW_LINES: usize = 5;
+
+pub fn handle(day: Day, pick: Option<String>, overwrite: bool) {
+    let Some(examples) = Examples::read(day) else {
+        eprintln!("No puzzle description found for day {day}. Run `cargo download {day}` first.");
+        process::exit(1);
+    };
+
+    if examples.blocks.is_empty() {
+        eprintln!("The puzzle description of day {day} does not contain any code blocks.");
+        process::exit(1);
+    }
+
+    match pick {
+        Some(pick) => write_examples(day, &examples, &pick, overwrite),
+        None => list_examples(day, &examples),
+    }
+}
+
+fn list_examples(day: Day, examples: &Examples) {
+    for (i, block) in examples.blocks.iter().enumerate() {
+        let lines: Vec<&str> = block.content.lines().collect();
+        println!(
+            "Block {} (part {}, {} lines):",
+            i + 1,
+            block.part,
+            lines.len()
+        );
+        for line in lines.iter().take(PREVIEW_LINES) {
+            println!("    {line}");
+        }
+        if lines.len() > PREVIEW_LINES {
+            println!("    ...");
+        }
+    }
+
+    println!("---");
+    print_answers(examples);
+    println!("🎄 Type `cargo examples {day} --pick <block>[,<block>]` to write example files.");
+}
+
+fn print_answers(examples: &Examples) {
+    for (i, answer) in examples.answers.iter().enumerate() {
+        match answer {
+            Some(answer) => println!("Proposed answer for part {}: {answer}", i + 1),
+            None => println!("No answer found for part {}.", i + 1),
+        }
+    }
+}
+
+fn write_examples(day: Day, examples: &Examples, pick: &str, overwrite: bool) {
+    let picked: Vec<usize> = match pick
+        .split(',')
+        .map(|i| i.trim().parse::<usize>())
+        .collect::<Result<_, _>>()
+    {
+        Ok(picked) => picked,
+        Err(_) => {
+            eprintln!("Unexpected block list `{pick}`. Format: cargo examples 1 --pick 1,3");
+            process::exit(1);
+        }
+    };
+
+    let Some(blocks) = picked
+        .iter()
+        .map(|i| examples.blocks.get(i.checked_sub(1)?))
+        .collect::<Option<Vec<_>>>()
+    else {
+        eprintln!(
+            "Unexpected block in `{pick}`, expecting 1 to {}.",
+            examples.blocks.len()
+        );
+        process::exit(1);
+    };
+
+    let paths = get_example_paths(day, blocks.len());
+
+    // scaffold creates empty example files, those may be replaced.
+    if let Some(path) = paths
+        .iter()
+        .find(|path| fs::metadata(path).is_ok_and(|m| m.len() > 0))
+        .filter(|_| !overwrite)
+    {
+        eprintln!("Example file \"{path}\" already exists. Pass --overwrite to replace it.");
+        process::exit(1);
+    }
+
+    for (path, block) in paths.iter().zip(&blocks) {
+        let dir = Path::new(path).parent().unwrap_or(Path::new("."));
+        if let Err(e) = fs::create_dir_all(dir).and_then(|()| fs::write(path, &block.content)) {
+            eprintln!("Failed to write example file \"{path}\": {e}");
+            process::exit(1);
+        }
+        println!("Wrote example file \"{path}\"");
+    }
+
+    println!("---");
+    print_answers(examples);
+
+    let module_path = format!("src/bin/{day}.rs");
+    let filled = fs::read_to_string(&module_path)
+        .ok()
+        .and_then(|source| fill_tests(&source, &examples.answers, blocks.len() == 2));
+
+    match filled.map(|source| fs::write(&module_path, source)) {
+        Some(Ok(())) => {
+            println!("🎄 Filled the expected answers into the tests of \"{module_path}\".")
+        }
+        Some(Err(e)) => eprintln!("Failed to update \"{module_path}\": {e}"),
+        None => {
+            println!("🎄 Tests of \"{module_path}\" were not changed, copy the answers into them.")
+        }
+    }
+}
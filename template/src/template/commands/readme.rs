@@ -0,0 +1,73 @@
+use std::process;
+
+use crate::template::answers::Progress;
+use crate::template::config::Config;
+use crate::template::readme::{
+    badges::Badges,
+    benchmarks::Benchmarks,
+    details::Details,
+    stars::{read_shown, Stars},
+    titles::Titles,
+    Engine,
+};
+use crate::template::timings::Timings;
+
+pub fn handle() {
+    let config = match Config::read_from_file() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    let table = config.benchmarks.unwrap_or_default();
+    let timings = Timings::read_from_file();
+    let progress = Progress::read();
+    let titles = Titles::read();
+
+    let benchmarks = Benchmarks {
+        timings: &timings,
+        table: &table,
+    };
+    let stars = Stars {
+        progress: &progress,
+    };
+    let details = Details {
+        progress: &progress,
+        timings: &timings,
+    };
+
+    // sections without locally recorded data are left untouched, e.g. in a fresh clone.
+    let mut engine = Engine::new().register(&Badges).allow_missing();
+
+    if !timings.data.is_empty() {
+        engine = engine.register(&benchmarks);
+    }
+
+    // stars from before answers were recorded would be dropped from the table.
+    let missing_stars = read_shown().is_some_and(|shown| progress.stars() < shown);
+
+    if !progress.days.is_empty() && !missing_stars {
+        engine = engine.register(&stars);
+    }
+
+    if !titles.titles.is_empty() {
+        engine = engine.register(&titles);
+    }
+
+    if !timings.data.is_empty() || !progress.days.is_empty() {
+        engine = engine.register(&details);
+    }
+
+    let result = engine.update();
+
+    match result {
+        Ok(true) => println!("Updated README."),
+        Ok(false) => println!("README is up to date."),
+        Err(e) => {
+            eprintln!("Failed to update README: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use crate::template::answers::Progress;
+use crate::template::badges;
+use crate::template::readme::{
+    stars::{read_shown, Stars},
+    Engine,
+};
+
+pub fn handle() {
+    let progress = Progress::read();
+
+    // stars from before answers were recorded would be dropped from the readme.
+    if let Some(shown) = read_shown().filter(|shown| progress.stars() < *shown) {
+        eprintln!(
+            "Kept the README progress: it shows {shown} ⭐, but only {} are recorded. Record the missing answers with `cargo answer` first.",
+            progress.stars()
+        );
+        return;
+    }
+
+    match Engine::new()
+        .register(&Stars {
+            progress: &progress,
+        })
+        .update()
+    {
+        Ok(_) => {
+            println!(
+                "Stored updated progress: {} ⭐, {} days solved.",
+                progress.stars(),
+                progress.days_solved()
+            );
+        }
+        Err(e) => {
+            eprintln!("Failed to store updated progress: {e}");
+        }
+    }
+
+    if let Err(e) = badges::store_progress(&progress) {
+        eprintln!("Failed to store progress badges: {e}");
+    }
+}
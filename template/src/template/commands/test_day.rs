@@ -0,0 +1,26 @@
+use std::process::{Command, Stdio};
+
+use crate::template::watch::{day_files, watch};
+use crate::template::Day;
+
+pub fn handle(day: Day, watching: bool) {
+    if watching {
+        watch(
+            &format!("cargo test-day {day}"),
+            || day_files(day, false),
+            || run(day),
+        );
+    } else if !run(day) {
+        std::process::exit(1);
+    }
+}
+
+/// Run the tests of a day, returns whether they passed.
+fn run(day: Day) -> bool {
+    Command::new("cargo")
+        .args(["test", "--bin", &day.to_string()])
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status()
+        .is_ok_and(|status| status.success())
+}
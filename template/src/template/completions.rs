@@ -0,0 +1,433 @@
+/// Module that generates shell completion scripts from the description of the command-line interface.
+/// Commands run through cargo aliases, so the scripts complete `cargo <command>` and leave the
+/// completion of other cargo commands to the completions of cargo itself.
+use std::{fmt::Write, str::FromStr};
+
+use crate::template::cli::{Arg, Command, Kind, Value};
+use crate::template::flavors;
+use crate::template::puzzle::{Year, FIRST_YEAR};
+use crate::template::AllDays;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+impl FromStr for Shell {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bash" => Ok(Shell::Bash),
+            "zsh" => Ok(Shell::Zsh),
+            "fish" => Ok(Shell::Fish),
+            _ => Err("expecting one of bash, zsh or fish".into()),
+        }
+    }
+}
+
+/// Completion script of the commands of `app` for a shell.
+/// Days are completed for an event of `length` days.
+pub fn generate(shell: Shell, app: &Command, length: u8) -> String {
+    let candidates = Candidates::new(length);
+    match shell {
+        Shell::Bash => bash(app, &candidates),
+        Shell::Zsh => zsh(app, &candidates),
+        Shell::Fish => fish(app, &candidates),
+    }
+}
+
+/// Values to complete, by the kind of value.
+struct Candidates {
+    days: String,
+    years: String,
+    templates: String,
+}
+
+impl Candidates {
+    fn new(length: u8) -> Self {
+        let days: Vec<_> = AllDays::with_length(length)
+            .map(|day| day.into_inner().to_string())
+            .collect();
+        let years: Vec<_> = (FIRST_YEAR..)
+            .map_while(Year::new)
+            .map(|year| year.to_string())
+            .collect();
+
+        Candidates {
+            days: days.join(" "),
+            years: years.join(" "),
+            templates: flavors::names().join(" "),
+        }
+    }
+
+    /// Space separated values, or `None` if any text may be entered.
+    fn of(&self, value: Value) -> Option<&str> {
+        match value {
+            Value::Day => Some(&self.days),
+            Value::Part => Some("1 2"),
+            Value::Year => Some(&self.years),
+            Value::Shell => Some("bash zsh fish"),
+            Value::Template => Some(&self.templates),
+            Value::Path | Value::Text(_) => None,
+        }
+    }
+}
+
+/// Options of a command, including the ones of the commands it belongs to.
+fn options<'a>(path: &[&'a Command]) -> Vec<&'a Arg> {
+    path.iter().flat_map(|command| command.options()).collect()
+}
+
+/// Every command below the root, with the commands it belongs to.
+fn commands(app: &Command) -> Vec<Vec<&Command>> {
+    fn walk<'a>(path: Vec<&'a Command>, commands: &mut Vec<Vec<&'a Command>>) {
+        let command = path[path.len() - 1];
+        for subcommand in command.subcommands {
+            let mut path = path.clone();
+            path.push(subcommand);
+            commands.push(path.clone());
+            walk(path, commands);
+        }
+    }
+
+    let mut commands = vec![];
+    walk(vec![app], &mut commands);
+    commands
+}
+
+fn names(commands: &[&Command]) -> String {
+    let names: Vec<_> = commands.iter().map(|command| command.name).collect();
+    names.join(" ")
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn bash(app: &Command, candidates: &Candidates) -> String {
+    let mut script = String::new();
+    let top: Vec<_> = app.subcommands.iter().collect();
+
+    writeln!(script, "_advent_of_code_cargo() {{").unwrap();
+    writeln!(script, "    local cur=\"${{COMP_WORDS[COMP_CWORD]}}\"").unwrap();
+    writeln!(script, "    local prev=\"${{COMP_WORDS[COMP_CWORD-1]}}\"").unwrap();
+    writeln!(script, "    case \"${{COMP_WORDS[*]:1:COMP_CWORD-1}}\" in").unwrap();
+
+    // the longest commands come first, so `auth status` is matched before `auth`.
+    let mut paths = commands(app);
+    paths.sort_by_key(|path| std::cmp::Reverse(path.len()));
+
+    for path in paths {
+        let command = path[path.len() - 1];
+        let pattern = names(&path[1..]);
+        writeln!(script, "        \"{pattern}\" | \"{pattern} \"*)").unwrap();
+
+        let options = options(&path);
+        writeln!(script, "            case \"$prev\" in").unwrap();
+        for arg in &options {
+            let Kind::Option(value) = arg.kind else {
+                continue;
+            };
+            let reply = match (value, candidates.of(value)) {
+                (Value::Path, _) => "COMPREPLY=($(compgen -f -- \"$cur\"))".to_string(),
+                (_, Some(words)) => format!("COMPREPLY=($(compgen -W \"{words}\" -- \"$cur\"))"),
+                (_, None) => "COMPREPLY=()".to_string(),
+            };
+            writeln!(script, "                {}) {reply}; return ;;", arg.name).unwrap();
+        }
+        writeln!(script, "            esac").unwrap();
+
+        let mut words: Vec<&str> = options.iter().map(|arg| arg.name).collect();
+        words.push("--help");
+        words.extend(command.subcommands.iter().map(|subcommand| subcommand.name));
+        for arg in command.positionals() {
+            if let Kind::Required(value) | Kind::Optional(value) = arg.kind {
+                words.extend(candidates.of(value));
+            }
+        }
+        writeln!(
+            script,
+            "            COMPREPLY=($(compgen -W \"{}\" -- \"$cur\")); return ;;",
+            words.join(" ")
+        )
+        .unwrap();
+    }
+
+    writeln!(script, "    esac").unwrap();
+    writeln!(script, "    declare -F _cargo >/dev/null && _cargo \"$@\"").unwrap();
+    writeln!(script, "    if [[ $COMP_CWORD -eq 1 ]]; then").unwrap();
+    writeln!(
+        script,
+        "        COMPREPLY+=($(compgen -W \"{}\" -- \"$cur\"))",
+        names(&top)
+    )
+    .unwrap();
+    writeln!(script, "    fi").unwrap();
+    writeln!(script, "}}").unwrap();
+    writeln!(script, "complete -o default -F _advent_of_code_cargo cargo").unwrap();
+
+    script
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Escape text for the descriptions of `_arguments` and `_describe`.
+fn zsh_escape(text: &str) -> String {
+    text.replace('\'', "'\\''")
+        .replace(':', "\\:")
+        .replace('[', "\\[")
+        .replace(']', "\\]")
+}
+
+fn zsh_action(value: Value, candidates: &Candidates) -> String {
+    match (value, candidates.of(value)) {
+        (Value::Path, _) => "_files".into(),
+        (_, Some(words)) => format!("({words})"),
+        (_, None) => " ".into(),
+    }
+}
+
+fn zsh(app: &Command, candidates: &Candidates) -> String {
+    let mut script = String::new();
+
+    writeln!(script, "#compdef cargo").unwrap();
+    writeln!(script, "_advent_of_code_cargo() {{").unwrap();
+    writeln!(script, "    case \"${{words[2,CURRENT-1]}}\" in").unwrap();
+
+    let mut paths = commands(app);
+    paths.sort_by_key(|path| std::cmp::Reverse(path.len()));
+
+    for path in paths {
+        let command = path[path.len() - 1];
+        let depth = path.len() - 1;
+        let pattern = names(&path[1..]);
+        writeln!(script, "        \"{pattern}\" | \"{pattern} \"*)").unwrap();
+        // `_arguments` expects the command itself in the first word.
+        writeln!(
+            script,
+            "            words=(${{words[{},-1]}}); (( CURRENT -= {} ))",
+            depth + 1,
+            depth
+        )
+        .unwrap();
+        write!(script, "            _arguments -s").unwrap();
+
+        for arg in options(&path) {
+            let help = zsh_escape(arg.help);
+            match arg.kind {
+                Kind::Flag => write!(script, " \\\n                '{}[{help}]'", arg.name),
+                Kind::Option(value) => write!(
+                    script,
+                    " \\\n                '{}[{help}]:{}:{}'",
+                    arg.name,
+                    value.placeholder(),
+                    zsh_action(value, candidates)
+                ),
+                _ => Ok(()),
+            }
+            .unwrap();
+        }
+        write!(
+            script,
+            " \\\n                '(- *)'{{-h,--help}}'[Print help]'"
+        )
+        .unwrap();
+
+        for (i, arg) in command.positionals().enumerate() {
+            let (Kind::Required(value) | Kind::Optional(value)) = arg.kind else {
+                continue;
+            };
+            let optional = if matches!(arg.kind, Kind::Optional(_)) {
+                ":"
+            } else {
+                ""
+            };
+            write!(
+                script,
+                " \\\n                '{}:{optional}{}:{}'",
+                i + 1,
+                zsh_escape(arg.help),
+                zsh_action(value, candidates)
+            )
+            .unwrap();
+        }
+
+        if !command.subcommands.is_empty() {
+            let subcommands: Vec<_> = command
+                .subcommands
+                .iter()
+                .map(|subcommand| subcommand.name)
+                .collect();
+            write!(
+                script,
+                " \\\n                '1:command:({})'",
+                subcommands.join(" ")
+            )
+            .unwrap();
+        }
+
+        writeln!(script, "\n            return ;;").unwrap();
+    }
+
+    writeln!(script, "    esac").unwrap();
+    writeln!(script, "    if (( CURRENT == 2 )); then").unwrap();
+    writeln!(script, "        local -a commands=(").unwrap();
+    for command in app.subcommands {
+        writeln!(
+            script,
+            "            '{}:{}'",
+            command.name,
+            zsh_escape(command.about)
+        )
+        .unwrap();
+    }
+    writeln!(script, "        )").unwrap();
+    writeln!(
+        script,
+        "        _describe -t aoc-commands 'advent of code command' commands"
+    )
+    .unwrap();
+    writeln!(script, "    fi").unwrap();
+    writeln!(script, "    (( $+functions[_cargo] )) && _cargo \"$@\"").unwrap();
+    writeln!(script, "}}").unwrap();
+    writeln!(script, "compdef _advent_of_code_cargo cargo").unwrap();
+
+    script
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn fish(app: &Command, candidates: &Candidates) -> String {
+    let mut script = String::new();
+    let top: Vec<_> = app.subcommands.iter().collect();
+
+    for command in &top {
+        writeln!(
+            script,
+            "complete -c cargo -n '__fish_use_subcommand' -f -a {} -d '{}'",
+            command.name,
+            fish_escape(command.about)
+        )
+        .unwrap();
+    }
+
+    for path in commands(app) {
+        let command = path[path.len() - 1];
+        let condition = fish_condition(&path[1..], command);
+
+        for arg in options(&path) {
+            let long = arg.name.trim_start_matches("--");
+            let help = fish_escape(arg.help);
+            match arg.kind {
+                Kind::Flag => writeln!(
+                    script,
+                    "complete -c cargo -n '{condition}' -f -l {long} -d '{help}'"
+                ),
+                Kind::Option(Value::Path) => writeln!(
+                    script,
+                    "complete -c cargo -n '{condition}' -r -F -l {long} -d '{help}'"
+                ),
+                Kind::Option(value) => writeln!(
+                    script,
+                    "complete -c cargo -n '{condition}' -x -l {long} -a '{}' -d '{help}'",
+                    candidates.of(value).unwrap_or_default()
+                ),
+                _ => Ok(()),
+            }
+            .unwrap();
+        }
+
+        for subcommand in command.subcommands {
+            writeln!(
+                script,
+                "complete -c cargo -n '{condition}' -f -a {} -d '{}'",
+                subcommand.name,
+                fish_escape(subcommand.about)
+            )
+            .unwrap();
+        }
+
+        for arg in command.positionals() {
+            let (Kind::Required(value) | Kind::Optional(value)) = arg.kind else {
+                continue;
+            };
+            if let Some(words) = candidates.of(value) {
+                writeln!(
+                    script,
+                    "complete -c cargo -n '{condition}' -f -a '{words}' -d '{}'",
+                    fish_escape(arg.help)
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    script
+}
+
+/// Condition that the command line is at a command, and not at one of its subcommands.
+fn fish_condition(commands: &[&Command], command: &Command) -> String {
+    let mut conditions: Vec<_> = commands
+        .iter()
+        .map(|command| format!("__fish_seen_subcommand_from {}", command.name))
+        .collect();
+    if !command.subcommands.is_empty() {
+        let names: Vec<_> = command
+            .subcommands
+            .iter()
+            .map(|subcommand| subcommand.name)
+            .collect();
+        conditions.push(format!(
+            "not __fish_seen_subcommand_from {}",
+            names.join(" ")
+        ));
+    }
+    conditions.join("; and ")
+}
+
+fn fish_escape(text: &str) -> String {
+    text.replace('\'', "\\'")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{generate, Shell};
+    use crate::template::cli::APP;
+
+    #[test]
+    fn completes_bash() {
+        let script = generate(Shell::Bash, &APP, 12);
+        assert!(script.contains("\"solve\" | \"solve \"*)"));
+        assert!(
+            script.contains("--submit) COMPREPLY=($(compgen -W \"1 2\" -- \"$cur\")); return ;;")
+        );
+        assert!(script.contains("1 2 3 4 5 6 7 8 9 10 11 12\""));
+        assert!(!script.contains(" 13"));
+        assert!(script.contains("complete -o default -F _advent_of_code_cargo cargo"));
+    }
+
+    #[test]
+    fn completes_zsh() {
+        let script = generate(Shell::Zsh, &APP, 25);
+        assert!(script.starts_with("#compdef cargo\n"));
+        assert!(script.contains("'1:Day of the puzzle, e.g. 1:(1 2 3"));
+        assert!(script.contains("'--submit[Submit the answer to a part]:part:(1 2)'"));
+        assert!(script.contains("'1:command:(login status)'"));
+        assert!(script.contains("'solve:Run the solution of a day.'"));
+    }
+
+    #[test]
+    fn completes_fish() {
+        let script = generate(Shell::Fish, &APP, 25);
+        assert!(script
+            .contains("-n '__fish_use_subcommand' -f -a solve -d 'Run the solution of a day.'"));
+        assert!(script.contains("-n '__fish_seen_subcommand_from solve' -f -a '1 2 3"));
+        assert!(script.contains(
+            "-n '__fish_seen_subcommand_from auth; and not __fish_seen_subcommand_from login status' -f -a login"
+        ));
+        assert!(script.contains("-n '__fish_seen_subcommand_from leaderboard' -r -F -l file"));
+    }
+}
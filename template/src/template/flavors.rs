@@ -0,0 +1,319 @@
+/// Module with the templates `scaffold` creates solutions from.
+/// Besides the default `src/template.txt`, there are built-in flavors for common kinds of puzzles,
+/// and user templates in `templates/<name>.txt`, which take precedence over built-in ones of the same name.
+use std::{
+    error::Error,
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::template::puzzle::Year;
+use crate::template::Day;
+
+/// Directory of user templates.
+pub static USER_TEMPLATE_DIR: &str = "templates";
+
+pub static DEFAULT_FLAVOR: &str = "default";
+
+const DEFAULT_TEMPLATE: &str =
+    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+
+/// Built-in flavors: name, description and template.
+pub const BUILT_IN: [(&str, &str, &str); 4] = [
+    (
+        "grid",
+        "2D grid with coordinates and directions",
+        include_str!("flavors/grid.txt"),
+    ),
+    (
+        "graph",
+        "graph with breadth-first search and shortest paths",
+        include_str!("flavors/graph.txt"),
+    ),
+    (
+        "parse-sections",
+        "input of two sections separated by a blank line",
+        include_str!("flavors/parse-sections.txt"),
+    ),
+    (
+        "vm",
+        "machine that runs a program of instructions",
+        include_str!("flavors/vm.txt"),
+    ),
+];
+
+/// A template of a solution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Flavor {
+    pub name: String,
+    /// Where the template was read from, `None` for templates that are part of the crate.
+    pub path: Option<PathBuf>,
+    pub template: String,
+}
+
+impl Flavor {
+    /// Find a template by name, the default one if no name is given.
+    pub fn find(name: Option<&str>) -> Result<Self, FlavorError> {
+        Self::find_in(Path::new(USER_TEMPLATE_DIR), name)
+    }
+
+    fn find_in(user_dir: &Path, name: Option<&str>) -> Result<Self, FlavorError> {
+        let name = name.unwrap_or(DEFAULT_FLAVOR);
+
+        if name.is_empty() || name.contains(['/', '\\', '.']) {
+            return Err(FlavorError::Unknown(name.into(), names_in(user_dir)));
+        }
+
+        let path = user_dir.join(format!("{name}.txt"));
+        match fs::read_to_string(&path) {
+            Ok(template) => {
+                return Ok(Flavor {
+                    name: name.into(),
+                    path: Some(path),
+                    template,
+                })
+            }
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                return Err(FlavorError::Read(path, e.to_string()))
+            }
+            Err(_) => {}
+        }
+
+        let template = if name == DEFAULT_FLAVOR {
+            DEFAULT_TEMPLATE
+        } else {
+            BUILT_IN
+                .iter()
+                .find(|(built_in, _, _)| *built_in == name)
+                .map(|(_, _, template)| *template)
+                .ok_or_else(|| FlavorError::Unknown(name.into(), names_in(user_dir)))?
+        };
+
+        Ok(Flavor {
+            name: name.into(),
+            path: None,
+            template: template.into(),
+        })
+    }
+}
+
+/// Names of all templates: the default, built-in flavors and user templates.
+pub fn names() -> Vec<String> {
+    names_in(Path::new(USER_TEMPLATE_DIR))
+}
+
+fn names_in(user_dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = [DEFAULT_FLAVOR]
+        .into_iter()
+        .chain(BUILT_IN.iter().map(|(name, _, _)| *name))
+        .map(String::from)
+        .collect();
+
+    let mut user: Vec<String> = fs::read_dir(user_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            name.strip_suffix(".txt").map(String::from)
+        })
+        .filter(|name| !names.contains(name))
+        .collect();
+    user.sort();
+
+    names.extend(user);
+    names
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum FlavorError {
+    /// A template of that name does not exist, with the names of the available ones.
+    Unknown(String, Vec<String>),
+    Read(PathBuf, String),
+}
+
+impl Error for FlavorError {}
+
+impl Display for FlavorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FlavorError::Unknown(name, names) => write!(
+                f,
+                "unknown template `{name}`, expecting one of: {}. User templates are read from `{USER_TEMPLATE_DIR}/<name>.txt`.",
+                names.join(", ")
+            ),
+            FlavorError::Read(path, e) => {
+                write!(f, "failed to read template \"{}\": {e}", path.display())
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Values of the placeholders of a template.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Placeholders {
+    pub year: Option<Year>,
+    pub day: Day,
+    /// Title of the puzzle, if its description was downloaded.
+    pub title: Option<String>,
+    /// Proposed answers for the examples, if the description was downloaded.
+    pub answers: [Option<String>; 2],
+}
+
+impl Placeholders {
+    /// Values of the placeholders, by name.
+    fn values(&self) -> [(&'static str, String); 6] {
+        // the tests of a template compare with `Option`s, which only numeric answers fit in.
+        let answer = |answer: &Option<String>| match answer {
+            Some(answer) if answer.parse::<u64>().is_ok() => format!("Some({answer})"),
+            _ => "None".into(),
+        };
+
+        [
+            ("%DAY_NUMBER%", self.day.into_inner().to_string()),
+            ("%DAY%", self.day.to_string()),
+            (
+                "%YEAR%",
+                self.year.map(|year| year.to_string()).unwrap_or_default(),
+            ),
+            ("%TITLE%", self.title.clone().unwrap_or_default()),
+            ("%EXAMPLE_ANSWER_1%", answer(&self.answers[0])),
+            ("%EXAMPLE_ANSWER_2%", answer(&self.answers[1])),
+        ]
+    }
+}
+
+/// Fill in the placeholders of a template. Returns the solution and the placeholders that are unknown.
+pub fn render(template: &str, placeholders: &Placeholders) -> (String, Vec<String>) {
+    let mut source = template.to_string();
+    for (placeholder, value) in placeholders.values() {
+        source = source.replace(placeholder, &value);
+    }
+
+    // unknown values leave trailing spaces, e.g. in a comment with the title.
+    let mut source: String = source
+        .lines()
+        .map(|line| format!("{}\n", line.trim_end()))
+        .collect();
+    if !template.ends_with('\n') {
+        source.pop();
+    }
+
+    let mut unknown: Vec<String> = vec![];
+    for placeholder in placeholders_in(&source) {
+        if !unknown.iter().any(|known| known == placeholder) {
+            unknown.push(placeholder.into());
+        }
+    }
+
+    (source, unknown)
+}
+
+/// Words enclosed in `%` that look like placeholders, e.g. `%AUTHOR%`.
+fn placeholders_in(source: &str) -> Vec<&str> {
+    let is_name = |name: &str| {
+        name.starts_with(|c: char| c.is_ascii_uppercase())
+            && name
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+    };
+
+    let mut placeholders = vec![];
+    for line in source.lines() {
+        let mut rest = line;
+        while let Some(start) = rest.find('%') {
+            let Some(len) = rest[start + 1..].find('%') else {
+                break;
+            };
+            let end = start + len + 2;
+            if is_name(&rest[start + 1..end - 1]) {
+                placeholders.push(&rest[start..end]);
+                rest = &rest[end..];
+            } else {
+                // the closing `%` may open the next placeholder.
+                rest = &rest[end - 1..];
+            }
+        }
+    }
+    placeholders
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs};
+
+    use super::{render, Flavor, FlavorError, Placeholders, BUILT_IN};
+    use crate::template::{puzzle::Year, Day};
+
+    fn placeholders() -> Placeholders {
+        Placeholders {
+            year: Year::new(2024),
+            day: Day::new(7).unwrap(),
+            title: Some("Bridge Repair".into()),
+            answers: [Some("3749".into()), Some("x,y".into())],
+        }
+    }
+
+    #[test]
+    fn renders_placeholders() {
+        let (source, unknown) = render(
+            "// %YEAR% day %DAY%: %TITLE%\nsolution!(%DAY_NUMBER%);\n%EXAMPLE_ANSWER_1% %EXAMPLE_ANSWER_2% %AUTHOR%\n",
+            &placeholders(),
+        );
+        assert_eq!(
+            source,
+            "// 2024 day 07: Bridge Repair\nsolution!(7);\nSome(3749) None %AUTHOR%\n"
+        );
+        assert_eq!(unknown, vec!["%AUTHOR%"]);
+
+        let untitled = Placeholders {
+            title: None,
+            ..placeholders()
+        };
+        assert_eq!(render("// %TITLE%\n", &untitled).0, "//\n");
+    }
+
+    #[test]
+    fn renders_built_in_flavors() {
+        for (name, _, template) in BUILT_IN {
+            let (source, unknown) = render(template, &placeholders());
+            assert!(unknown.is_empty(), "{name}: {unknown:?}");
+            assert!(source.contains("advent_of_code::solution!(7);"), "{name}");
+            assert!(source.contains("assert_eq!(result, Some(3749));"), "{name}");
+        }
+    }
+
+    #[test]
+    fn finds_flavors() {
+        let dir = env::temp_dir().join(format!("aoc-flavors-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("grid.txt"), "my grid").unwrap();
+        fs::write(dir.join("dp.txt"), "my dp").unwrap();
+
+        let grid = Flavor::find_in(&dir, Some("grid"));
+        let vm = Flavor::find_in(&dir, Some("vm"));
+        let default = Flavor::find_in(&dir, None);
+        let unknown = Flavor::find_in(&dir, Some("nope"));
+        let outside = Flavor::find_in(&dir, Some("../secret"));
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(grid.unwrap().template, "my grid");
+        assert_eq!(vm.unwrap().path, None);
+        assert!(default.unwrap().template.contains("%DAY_NUMBER%"));
+        assert_eq!(
+            unknown,
+            Err(FlavorError::Unknown(
+                "nope".into(),
+                ["default", "grid", "graph", "parse-sections", "vm", "dp"]
+                    .map(String::from)
+                    .to_vec()
+            ))
+        );
+        assert!(matches!(outside, Err(FlavorError::Unknown(..))));
+    }
+}
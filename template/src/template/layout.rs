@@ -0,0 +1,134 @@
+/// Module that describes where the project lives inside its repository.
+/// A repository can either be a single project, or contain one project per year (e.g. `./2024`).
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+use crate::template::puzzle::{self, Year};
+use crate::template::Day;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Layout {
+    /// Root of the repository, i.e. the directory containing `README.md`.
+    pub root: PathBuf,
+    /// Path of the project relative to the repository root, `/`-separated. Empty if they are the same.
+    pub prefix: String,
+    /// The active year, if known.
+    pub year: Option<Year>,
+    /// Whether readme sections and badges are kept apart per year: in repositories with one project per year,
+    /// and when working on another year than the project is configured for.
+    pub per_year: bool,
+}
+
+impl Layout {
+    /// Discover the layout for the current directory.
+    /// The repository root is the closest ancestor containing a `.git` entry,
+    /// falling back to the current directory if there is none.
+    pub fn discover() -> std::io::Result<Self> {
+        let year = match Year::from_env() {
+            Ok(year) => Some(year),
+            Err(puzzle::YearError::Missing) => None,
+            Err(e) => return Err(std::io::Error::other(e)),
+        };
+
+        let cwd = env::current_dir()?;
+
+        let root = cwd
+            .ancestors()
+            .find(|dir| dir.join(".git").exists())
+            .unwrap_or(&cwd)
+            .to_path_buf();
+
+        let mut layout = Self::new(&root, &cwd, year);
+        layout.per_year |= puzzle::other_year().is_some();
+        Ok(layout)
+    }
+
+    pub fn new(root: &Path, project_dir: &Path, year: Option<Year>) -> Self {
+        let prefix = project_dir
+            .strip_prefix(root)
+            .map(|p| {
+                p.components()
+                    .map(|c| c.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/")
+            })
+            .unwrap_or_default();
+
+        Layout {
+            root: root.to_path_buf(),
+            per_year: !prefix.is_empty(),
+            prefix,
+            year,
+        }
+    }
+
+    pub fn readme_path(&self) -> PathBuf {
+        self.root.join("README.md")
+    }
+
+    /// Returns a link to a path inside the project, relative to the repository root.
+    pub fn link(&self, path: &str) -> String {
+        if self.prefix.is_empty() {
+            format!("./{path}")
+        } else {
+            format!("./{}/{path}", self.prefix)
+        }
+    }
+
+    /// Returns a link to the solution of a day, relative to the repository root.
+    pub fn link_to_bin(&self, day: Day) -> String {
+        self.link(&format!("src/bin/{day}.rs"))
+    }
+
+    /// Returns the path of a badge relative to the repository root, e.g. `assets/stars.svg`.
+    /// Repositories with one project per year keep the badges of every year in a separate directory.
+    fn badge_file(&self, name: &str) -> String {
+        match self.year {
+            Some(year) if self.per_year => format!("assets/{year}/{name}.svg"),
+            _ => format!("assets/{name}.svg"),
+        }
+    }
+
+    pub fn badge_path(&self, name: &str) -> PathBuf {
+        self.root.join(self.badge_file(name))
+    }
+
+    /// Returns a link to a badge, relative to the repository root.
+    pub fn badge_link(&self, name: &str) -> String {
+        format!("./{}", self.badge_file(name))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::Path;
+
+    use super::Layout;
+    use crate::day;
+    use crate::template::puzzle::Year;
+
+    #[test]
+    fn links_single_project_repositories() {
+        let layout = Layout::new(Path::new("/repo"), Path::new("/repo"), Year::new(2024));
+        assert_eq!(layout.prefix, "");
+        assert_eq!(layout.link_to_bin(day!(1)), "./src/bin/01.rs");
+        assert_eq!(layout.readme_path(), Path::new("/repo/README.md"));
+        assert_eq!(
+            layout.badge_path("stars"),
+            Path::new("/repo/assets/stars.svg")
+        );
+    }
+
+    #[test]
+    fn links_multi_year_repositories() {
+        let layout = Layout::new(Path::new("/repo"), Path::new("/repo/2024"), Year::new(2024));
+        assert_eq!(layout.prefix, "2024");
+        assert_eq!(layout.link_to_bin(day!(12)), "./2024/src/bin/12.rs");
+        assert_eq!(layout.readme_path(), Path::new("/repo/README.md"));
+        assert_eq!(layout.badge_link("stars"), "./assets/2024/stars.svg");
+    }
+}
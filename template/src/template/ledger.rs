@@ -0,0 +1,389 @@
+/// Module that keeps a per-day ledger of submitted answers in `data/submissions`, e.g. `01.json`.
+/// The ledger is used to refuse submissions that are known to be wrong before they cost another lockout.
+use std::{collections::HashMap, fmt::Display, fs, io, path::Path, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::aoc_client::{format_countdown, Hint, Outcome};
+use crate::template::{profile, Day};
+
+/// Submissions are made with the session of the active profile, so each profile has its own ledger.
+#[must_use]
+pub fn get_ledger_path(day: Day) -> String {
+    profile::data_path(
+        "submissions",
+        &format!("{day}.json"),
+        profile::current().as_deref(),
+    )
+}
+
+/// Verdict of a submission, as far as the ledger is concerned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+}
+
+impl Verdict {
+    fn as_str(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::Incorrect => "incorrect",
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too_high" => Ok(Verdict::TooHigh),
+            "too_low" => Ok(Verdict::TooLow),
+            "incorrect" => Ok(Verdict::Incorrect),
+            _ => Err(format!("Unknown verdict `{s}`.")),
+        }
+    }
+}
+
+/// A submitted answer and its verdict.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    pub timestamp: u64,
+}
+
+/// Reasons to refuse a submission.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved(String),
+    KnownWrong(Verdict),
+    TooHigh(String),
+    TooLow(String),
+    Cooldown(u64),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved(answer) => {
+                write!(f, "this part was already solved with `{answer}`.")
+            }
+            Refusal::KnownWrong(verdict) => write!(
+                f,
+                "this answer was already submitted and judged {}.",
+                verdict.as_str().replace('_', " ")
+            ),
+            Refusal::TooHigh(bound) => {
+                write!(
+                    f,
+                    "`{bound}` was already too high, this answer is not lower."
+                )
+            }
+            Refusal::TooLow(bound) => {
+                write!(
+                    f,
+                    "`{bound}` was already too low, this answer is not higher."
+                )
+            }
+            Refusal::Cooldown(seconds) => write!(
+                f,
+                "submissions are locked after a wrong answer, {} left to wait.",
+                format_countdown(*seconds)
+            ),
+        }
+    }
+}
+
+/// Ledger of the submissions of a day.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Ledger {
+    pub submissions: Vec<Submission>,
+    /// Unix timestamp until which the website refuses submissions.
+    pub locked_until: u64,
+}
+
+impl Ledger {
+    /// Read the ledger of a day. If not present, returns an empty ledger.
+    /// A ledger that cannot be read or parsed is an error, so known wrong answers are not forgotten.
+    pub fn read(day: Day) -> Result<Self, String> {
+        let path = get_ledger_path(day);
+        match fs::read_to_string(&path) {
+            Ok(json) => Ledger::try_from(json),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Ledger::default()),
+            Err(e) => Err(format!("{e}.")),
+        }
+        .map_err(|e| format!("failed to read submission ledger \"{path}\": {e}"))
+    }
+
+    pub fn store(&self, day: Day) -> io::Result<()> {
+        let path = get_ledger_path(day);
+        if let Some(dir) = Path::new(&path).parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let json = JsonValue::from(self);
+        fs::write(path, json.format().map_err(io::Error::other)?)
+    }
+
+    fn for_part(&self, part: u8) -> impl Iterator<Item = &Submission> {
+        self.submissions.iter().filter(move |s| s.part == part)
+    }
+
+    /// Check whether an answer is worth submitting at unix time `now`.
+    pub fn check(&self, part: u8, answer: &str, now: u64) -> Result<(), Refusal> {
+        if let Some(solved) = self.for_part(part).find(|s| s.verdict == Verdict::Correct) {
+            return Err(Refusal::AlreadySolved(solved.answer.clone()));
+        }
+
+        if let Some(known) = self.for_part(part).find(|s| s.answer == answer) {
+            return Err(Refusal::KnownWrong(known.verdict));
+        }
+
+        if let Ok(value) = answer.parse::<i128>() {
+            let bound = |verdict: Verdict| {
+                self.for_part(part)
+                    .filter(move |s| s.verdict == verdict)
+                    .filter_map(|s| Some((s.answer.parse::<i128>().ok()?, &s.answer)))
+            };
+
+            if let Some((_, high)) = bound(Verdict::TooHigh).min().filter(|(h, _)| value >= *h) {
+                return Err(Refusal::TooHigh(high.clone()));
+            }
+
+            if let Some((_, low)) = bound(Verdict::TooLow).max().filter(|(l, _)| value <= *l) {
+                return Err(Refusal::TooLow(low.clone()));
+            }
+        }
+
+        if self.locked_until > now {
+            return Err(Refusal::Cooldown(self.locked_until - now));
+        }
+
+        Ok(())
+    }
+
+    /// Record the outcome of a submission made at unix time `now`.
+    pub fn record(&mut self, part: u8, answer: &str, outcome: Outcome, now: u64) {
+        let (verdict, wait) = match outcome {
+            Outcome::Correct => (Verdict::Correct, 0),
+            Outcome::Incorrect { hint, wait } => {
+                let verdict = match hint {
+                    Some(Hint::TooHigh) => Verdict::TooHigh,
+                    Some(Hint::TooLow) => Verdict::TooLow,
+                    None => Verdict::Incorrect,
+                };
+                (verdict, wait)
+            }
+            Outcome::RateLimited { wait } => {
+                self.locked_until = now + wait;
+                return;
+            }
+            Outcome::AlreadySolved | Outcome::NotLoggedIn | Outcome::Unknown => return,
+        };
+
+        self.submissions.push(Submission {
+            part,
+            answer: answer.to_string(),
+            verdict,
+            timestamp: now,
+        });
+        self.locked_until = now + wait;
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Ledger> for JsonValue {
+    fn from(value: &Ledger) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "submissions".into(),
+            JsonValue::Array(value.submissions.iter().map(JsonValue::from).collect()),
+        );
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "locked_until".into(),
+            JsonValue::Number(value.locked_until as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Ledger {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let submissions = json
+            .get("submissions")
+            .ok_or("expected ledger to have key `submissions`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `submissions` to be an array.")?
+            .iter()
+            .map(Submission::try_from)
+            .collect::<Result<_, _>>()?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let locked_until = json
+            .get("locked_until")
+            .and_then(|v| v.get::<f64>())
+            .map_or(0, |v| *v as u64);
+
+        Ok(Ledger {
+            submissions,
+            locked_until,
+        })
+    }
+}
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "verdict".into(),
+            JsonValue::String(value.verdict.as_str().into()),
+        );
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .copied()
+                .ok_or(format!("Expected submission to have number key `{key}`."))
+        };
+
+        let string = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<String>())
+                .cloned()
+                .ok_or(format!("Expected submission to have string key `{key}`."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(Submission {
+            part: number("part")? as u8,
+            answer: string("answer")?,
+            verdict: string("verdict")?.parse()?,
+            timestamp: number("timestamp")? as u64,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{JsonValue, Ledger, Refusal, Submission, Verdict};
+    use crate::template::aoc_client::{Hint, Outcome};
+
+    fn submission(part: u8, answer: &str, verdict: Verdict) -> Submission {
+        Submission {
+            part,
+            answer: answer.into(),
+            verdict,
+            timestamp: 0,
+        }
+    }
+
+    fn get_mock_ledger() -> Ledger {
+        Ledger {
+            submissions: vec![
+                submission(1, "100", Verdict::TooHigh),
+                submission(1, "10", Verdict::TooLow),
+                submission(1, "abc", Verdict::Incorrect),
+                submission(2, "7", Verdict::Correct),
+            ],
+            locked_until: 1000,
+        }
+    }
+
+    #[test]
+    fn refuses_known_answers() {
+        let ledger = get_mock_ledger();
+        assert_eq!(
+            ledger.check(1, "abc", 2000),
+            Err(Refusal::KnownWrong(Verdict::Incorrect))
+        );
+        assert_eq!(
+            ledger.check(2, "8", 2000),
+            Err(Refusal::AlreadySolved("7".into()))
+        );
+    }
+
+    #[test]
+    fn refuses_answers_out_of_bounds() {
+        let ledger = get_mock_ledger();
+        assert_eq!(
+            ledger.check(1, "150", 2000),
+            Err(Refusal::TooHigh("100".into()))
+        );
+        assert_eq!(
+            ledger.check(1, "3", 2000),
+            Err(Refusal::TooLow("10".into()))
+        );
+        assert_eq!(ledger.check(1, "50", 2000), Ok(()));
+    }
+
+    #[test]
+    fn refuses_during_cooldown() {
+        let ledger = get_mock_ledger();
+        assert_eq!(ledger.check(1, "50", 955), Err(Refusal::Cooldown(45)));
+        assert_eq!(
+            Refusal::Cooldown(65).to_string(),
+            "submissions are locked after a wrong answer, 1m 5s left to wait."
+        );
+    }
+
+    #[test]
+    fn records_and_serializes() {
+        let mut ledger = Ledger::default();
+        ledger.record(
+            1,
+            "42",
+            Outcome::Incorrect {
+                hint: Some(Hint::TooHigh),
+                wait: 60,
+            },
+            100,
+        );
+        assert_eq!(ledger.locked_until, 160);
+        assert_eq!(
+            ledger.check(1, "43", 200),
+            Err(Refusal::TooHigh("42".into()))
+        );
+
+        let json = JsonValue::from(&ledger).format().unwrap();
+        assert_eq!(Ledger::try_from(json).unwrap(), ledger);
+    }
+}
@@ -1,25 +1,57 @@
 use std::{env, fs};
 
-pub mod aoc_cli;
+pub mod alloc;
+pub mod aoc_client;
+pub mod cli;
 pub mod commands;
+pub mod completions;
+#[cfg(feature = "mock-server")]
+pub mod mock_server;
+pub mod profile;
+pub mod puzzle;
 pub mod runner;
+pub mod session;
 
 pub use day::*;
 
+mod answers;
+mod badges;
+mod budgets;
+mod clock;
+mod columns;
+mod compare;
+mod config;
 mod day;
-mod readme_benchmarks;
+mod description;
+mod examples;
+mod flavors;
+mod layout;
+mod leaderboard;
+mod ledger;
+mod readme;
 mod run_multi;
+mod scaffold;
+mod status;
 mod timings;
+mod watch;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
+pub const ANSI_RED: &str = "\x1b[31m";
+pub const ANSI_GREEN: &str = "\x1b[32m";
 
 /// Helper function that reads a text file to a string.
+/// Inputs are read from the directory of the active profile, if any.
 #[must_use]
 pub fn read_file(folder: &str, day: Day) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let profile = profile::current().filter(|_| folder == "inputs");
+    let filepath = cwd.join(profile::data_path(
+        folder,
+        &format!("{day}.txt"),
+        profile.as_deref(),
+    ));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
@@ -29,7 +61,7 @@ pub fn read_file(folder: &str, day: Day) -> String {
 pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
-        .join("data")
+        .join(puzzle::data_dir())
         .join(folder)
         .join(format!("{day}-{part}.txt"));
     let f = fs::read_to_string(filepath);
@@ -59,10 +91,15 @@ macro_rules! solution {
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
+        #[cfg(all(feature = "count-allocations", not(feature = "dhat-heap")))]
+        #[global_allocator]
+        static ALLOC: $crate::template::alloc::CountingAlloc = $crate::template::alloc::CountingAlloc;
+
         fn main() {
             use $crate::template::runner::*;
+            let args = $crate::template::cli::SolutionArgs::from_env();
             let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            $( run_part($func, &input, DAY, $part, &args); )*
         }
     };
 }
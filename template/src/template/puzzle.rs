@@ -0,0 +1,299 @@
+/// Module for the year of an event, and puzzles identified by year and day.
+/// The active year is read from `AOC_YEAR`, which is set in `.cargo/config.toml` and can be overridden with `--year`.
+/// Data of a year other than the one the project is configured for lives in `data/<year>`, e.g. `data/2023/inputs/01.txt`.
+use std::{env, error::Error, fmt::Display, str::FromStr};
+
+use crate::template::{aoc_client, clock, event_length, AllDays, Day};
+
+pub static ENV_YEAR: &str = "AOC_YEAR";
+
+/// The year the project is configured for, remembered when `--year` overrides `AOC_YEAR`.
+static ENV_PROJECT_YEAR: &str = "AOC_PROJECT_YEAR";
+
+/// Year of the first event.
+pub const FIRST_YEAR: u16 = 2015;
+
+/// A year with an Advent of Code event, from 2015 to the current year.
+///
+/// ```
+/// # use advent_of_code::template::puzzle::Year;
+/// assert!(Year::new(2015).is_some());
+/// assert!(Year::new(2014).is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] if there is an event for it, returns [`None`] otherwise.
+    /// The event of the current year counts, even before it starts.
+    pub fn new(year: u16) -> Option<Self> {
+        (FIRST_YEAR..=current_year(clock::now()))
+            .contains(&year)
+            .then_some(Self(year))
+    }
+
+    /// Read the active year from `AOC_YEAR`.
+    pub fn from_env() -> Result<Self, YearError> {
+        match env::var(ENV_YEAR) {
+            Ok(year) => year.parse().map_err(|_| YearError::Invalid(year)),
+            Err(_) => Err(YearError::Missing),
+        }
+    }
+
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// Number of days of the event.
+    pub fn length(self) -> u8 {
+        event_length(self.0)
+    }
+
+    /// Every day of the event.
+    pub fn days(self) -> AllDays {
+        AllDays::with_length(self.length())
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.trim().parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "expecting a year with an event, between {FIRST_YEAR} and {}",
+            current_year(clock::now())
+        )
+    }
+}
+
+/// An error which can be returned when reading the active [`Year`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum YearError {
+    Missing,
+    Invalid(String),
+}
+
+impl Error for YearError {}
+
+impl Display for YearError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            YearError::Missing => write!(
+                f,
+                "AOC_YEAR is not set. Set it in `.cargo/config.toml` or pass --year."
+            ),
+            YearError::Invalid(year) => write!(
+                f,
+                "AOC_YEAR `{year}` is not a year with an event, expecting one between {FIRST_YEAR} and {}.",
+                current_year(clock::now())
+            ),
+        }
+    }
+}
+
+/// Calendar year at unix time `now`.
+fn current_year(now: u64) -> u16 {
+    let (year, _, _) = aoc_client::civil_from_days(i64::try_from(now / 86400).unwrap_or(0));
+    u16::try_from(year).unwrap_or(u16::MAX)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A puzzle of an event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Puzzle {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl Puzzle {
+    pub fn new(year: Year, day: Day) -> Self {
+        Puzzle { year, day }
+    }
+
+    /// URL of the puzzle on a website, e.g. `https://adventofcode.com/2024/day/1`.
+    pub fn url(&self, base_url: &str) -> String {
+        format!("{base_url}/{}/day/{}", self.year, self.day.into_inner())
+    }
+
+    /// Unix time at which the puzzle unlocks.
+    pub fn unlocks_at(&self) -> u64 {
+        aoc_client::unlocks_at(self.year.into_inner(), self.day)
+    }
+}
+
+/// Puzzles unlock at midnight EST (UTC-5), so days of the event start then.
+const SERVER_UTC_OFFSET: u64 = 5 * 3600;
+
+/// The puzzle of the day at unix time `now`, if an event is running.
+pub fn today(now: u64) -> Option<Puzzle> {
+    let (year, month, day) = server_date(now);
+    let year = Year(u16::try_from(year).ok()?);
+
+    if year.0 < FIRST_YEAR || month != 12 || day > year.length() {
+        return None;
+    }
+    Some(Puzzle::new(year, Day::new(day)?))
+}
+
+/// The puzzle that unlocks next after unix time `now`.
+pub fn next_unlock(now: u64) -> Puzzle {
+    let (year, month, day) = server_date(now);
+    let year = Year(u16::try_from(year).unwrap_or(u16::MAX).max(FIRST_YEAR));
+
+    let (year, day) = match month {
+        12 if day < year.length() => (year, day + 1),
+        12 => (Year(year.0 + 1), 1),
+        _ => (year, 1),
+    };
+
+    Puzzle::new(year, Day::__new_unchecked(day))
+}
+
+/// Date at unix time `now` in the time zone of the website.
+fn server_date(now: u64) -> (i64, u8, u8) {
+    let days = now.saturating_sub(SERVER_UTC_OFFSET) / 86400;
+    aoc_client::civil_from_days(i64::try_from(days).unwrap_or(0))
+}
+
+impl Display for Puzzle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} day {}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Make a year the active one, for this process and the ones it spawns.
+/// The configured year is remembered, so data of other years can be kept apart.
+pub fn activate(year: Year) {
+    if env::var_os(ENV_PROJECT_YEAR).is_none() {
+        if let Ok(project_year) = env::var(ENV_YEAR) {
+            env::set_var(ENV_PROJECT_YEAR, project_year);
+        }
+    }
+    env::set_var(ENV_YEAR, year.to_string());
+}
+
+/// The active year, if `--year` selected one other than the project is configured for.
+pub fn other_year() -> Option<Year> {
+    let project_year = env::var(ENV_PROJECT_YEAR).ok()?;
+    Year::from_env()
+        .ok()
+        .filter(|year| year.to_string() != project_year)
+}
+
+/// Directory of the data of the active year: `data`, or e.g. `data/2023` for another year.
+#[must_use]
+pub fn data_dir() -> String {
+    match other_year() {
+        Some(year) => format!("data/{year}"),
+        None => "data".into(),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{current_year, next_unlock, today, Puzzle, Year};
+    use crate::{day, template::Day};
+
+    #[test]
+    fn validates_years() {
+        assert_eq!(Year::new(2015).map(Year::into_inner), Some(2015));
+        assert_eq!(Year::new(2014), None);
+        assert_eq!(Year::new(9999), None);
+        assert!("2024".parse::<Year>().is_ok());
+        assert!("24".parse::<Year>().is_err());
+        assert!("next".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn knows_event_lengths() {
+        assert_eq!(Year(2024).days().count(), 25);
+        assert_eq!(Year(2025).days().count(), 12);
+    }
+
+    #[test]
+    fn finds_current_year() {
+        // 2024-12-31T23:59:59Z and 2025-01-01T00:00:00Z.
+        assert_eq!(current_year(1_735_689_599), 2024);
+        assert_eq!(current_year(1_735_689_600), 2025);
+    }
+
+    #[test]
+    fn finds_puzzle_of_the_day() {
+        let unlock = |year, day| Puzzle::new(Year(year), day).unlocks_at();
+        let puzzle = |year, day| Some(Puzzle::new(Year(year), day));
+
+        assert_eq!(today(unlock(2024, day!(1)) - 1), None);
+        assert_eq!(today(unlock(2024, day!(1))), puzzle(2024, day!(1)));
+        assert_eq!(today(unlock(2024, day!(2)) - 1), puzzle(2024, day!(1)));
+        assert_eq!(
+            today(unlock(2024, day!(12)) + 86400),
+            puzzle(2024, Day::new(13).unwrap())
+        );
+        // the 2025 event ends on the 12th.
+        assert_eq!(today(unlock(2025, day!(12)) + 86400), None);
+        assert_eq!(today(unlock(2024, day!(1)) + 40 * 86400), None);
+    }
+
+    #[test]
+    fn finds_next_unlock() {
+        // built directly, as `Year::new` rejects events that have not started at the time the tests run.
+        let unlock = |year, day| Puzzle::new(Year(year), day).unlocks_at();
+        let puzzle = |year, day| Puzzle::new(Year(year), day);
+
+        // one second before midnight EST.
+        assert_eq!(
+            next_unlock(unlock(2024, day!(5)) - 1),
+            puzzle(2024, day!(5))
+        );
+        assert_eq!(next_unlock(unlock(2024, day!(5))), puzzle(2024, day!(6)));
+        assert_eq!(
+            next_unlock(unlock(2024, day!(1)) - 86400 * 30),
+            puzzle(2024, day!(1))
+        );
+        assert_eq!(
+            next_unlock(unlock(2024, day!(1)) + 24 * 86400),
+            puzzle(2025, day!(1))
+        );
+        assert_eq!(
+            next_unlock(unlock(2025, day!(1)) + 11 * 86400),
+            puzzle(2026, day!(1))
+        );
+    }
+
+    #[test]
+    fn links_puzzles() {
+        let puzzle = Puzzle::new(Year::new(2023).unwrap(), day!(7));
+        assert_eq!(puzzle.to_string(), "2023 day 07");
+        assert_eq!(
+            puzzle.url("https://adventofcode.com"),
+            "https://adventofcode.com/2023/day/7"
+        );
+        // midnight EST.
+        assert_eq!(puzzle.unlocks_at(), 1_701_925_200);
+    }
+}
@@ -0,0 +1,109 @@
+/// Module that renders a list of the puzzles downloaded to `data/puzzles`, with their titles.
+use std::collections::BTreeMap;
+
+use crate::template::description::read_title;
+use crate::template::layout::Layout;
+use crate::template::puzzle::Year;
+use crate::template::readme::Renderer;
+use crate::template::{all_days, Day};
+
+pub static SECTION: &str = "puzzle titles";
+
+/// Renders the `puzzle titles` sections.
+#[derive(Clone, Debug, Default)]
+pub struct Titles {
+    pub titles: BTreeMap<Day, String>,
+}
+
+impl Titles {
+    /// Collect the titles of all downloaded puzzles.
+    pub fn read() -> Self {
+        let titles = all_days()
+            .filter_map(|day| Some((day, read_title(day)?)))
+            .collect();
+
+        Titles { titles }
+    }
+}
+
+impl Renderer for Titles {
+    fn name(&self) -> &str {
+        SECTION
+    }
+
+    fn render(&self, layout: &Layout, year: Option<Year>) -> Result<Vec<String>, String> {
+        Ok(construct_table("##", year, layout, &self.titles))
+    }
+}
+
+fn construct_table(
+    prefix: &str,
+    year: Option<Year>,
+    layout: &Layout,
+    titles: &BTreeMap<Day, String>,
+) -> Vec<String> {
+    let header = match year {
+        Some(year) => format!("{prefix} {year} Puzzles"),
+        None => format!("{prefix} Puzzles"),
+    };
+
+    let mut lines: Vec<String> = vec![
+        header,
+        String::new(),
+        "| Day | Title |".into(),
+        "| :---: | :--- |".into(),
+    ];
+
+    for (day, title) in titles {
+        lines.push(format!(
+            "| [Day {}]({}) | {title} |",
+            day.into_inner(),
+            layout.link_to_bin(*day)
+        ));
+    }
+
+    lines
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{collections::BTreeMap, path::Path};
+
+    use super::{Titles, SECTION};
+    use crate::{
+        day,
+        template::{
+            layout::Layout,
+            puzzle::Year,
+            readme::{year_marker, Engine},
+        },
+    };
+
+    #[test]
+    fn format_titles() {
+        let layout = Layout::new(Path::new("/repo"), Path::new("/repo/2024"), Year::new(2024));
+        let titles = Titles {
+            titles: BTreeMap::from([
+                (day!(1), "Historian Hysteria".into()),
+                (day!(3), "Mull It Over".into()),
+            ]),
+        };
+        let marker = year_marker(SECTION, 2024);
+        let s = format!("{marker}\n{marker}");
+        let s = Engine::new().register(&titles).render(&s, &layout).unwrap();
+        let expected = [
+            "<!--- puzzle titles 2024 --->",
+            "## 2024 Puzzles",
+            "",
+            "| Day | Title |",
+            "| :---: | :--- |",
+            "| [Day 1](./2024/src/bin/01.rs) | Historian Hysteria |",
+            "| [Day 3](./2024/src/bin/03.rs) | Mull It Over |",
+            "<!--- puzzle titles 2024 --->",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+}
@@ -7,7 +7,12 @@ use super::{
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
+pub fn run_multi(
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    is_timed: bool,
+    count_allocations: bool,
+) -> Option<Timings> {
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
 
     let mut need_space = false;
@@ -24,7 +29,8 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
             println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
             println!("------");
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+            let output =
+                child_commands::run_solution(day, is_timed, is_release, count_allocations).unwrap();
 
             if output.is_empty() {
                 println!("Not solved.");
@@ -68,7 +74,7 @@ pub fn get_path_for_bin(day: Day) -> String {
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
 pub mod child_commands {
     use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use crate::template::{cli::SolutionArgs, timings::PartStats, Day, ANSI_BOLD, ANSI_RESET};
     use std::{
         io::{BufRead, BufReader},
         path::Path,
@@ -77,7 +83,12 @@ pub mod child_commands {
     };
 
     /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    pub fn run_solution(
+        day: Day,
+        is_timed: bool,
+        is_release: bool,
+        count_allocations: bool,
+    ) -> Result<Vec<String>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
         if !Path::new(&get_path_for_bin(day)).exists() {
             return Ok(vec![]);
@@ -90,12 +101,21 @@ pub mod child_commands {
             args.push("--release");
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+        if count_allocations {
+            args.push("--features");
+            args.push("count-allocations");
         }
 
+        // mirror `--time` flag to child invocations.
+        let solution_args = SolutionArgs {
+            time: is_timed,
+            ..SolutionArgs::default()
+        }
+        .to_args();
+
+        args.push("--");
+        args.extend(solution_args.iter().map(String::as_str));
+
         // spawn child command with piped stdout/stderr.
         // forward output to stdout/stderr while grabbing stdout lines.
 
@@ -134,6 +154,8 @@ pub mod child_commands {
             part_1: None,
             part_2: None,
             total_nanos: 0_f64,
+            part_1_stats: None,
+            part_2_stats: None,
         };
 
         output
@@ -149,13 +171,15 @@ pub mod child_commands {
                 };
 
                 let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
+                Some((part, timing_str, nanos, parse_stats(l, nanos)))
             })
-            .for_each(|(part, timing_str, nanos)| {
+            .for_each(|(part, timing_str, nanos, stats)| {
                 if part.contains("Part 1") {
                     timings.part_1 = Some(timing_str.into());
+                    timings.part_1_stats = stats;
                 } else if part.contains("Part 2") {
                     timings.part_2 = Some(timing_str.into());
+                    timings.part_2_stats = stats;
                 }
 
                 timings.total_nanos += nanos;
@@ -164,27 +188,82 @@ pub mod child_commands {
         timings
     }
 
+    /// Parse the answers printed by a solution, e.g. `Part 1: \x1b[1m42\x1b[0m (1.2ms)`.
+    /// Answers that span multiple lines are not parsed.
+    pub fn parse_answers(output: &[String]) -> [Option<String>; 2] {
+        let mut answers = [None, None];
+
+        for line in output {
+            // intermediate results are overwritten with a carriage return.
+            let line = line.rsplit('\r').next().unwrap_or(line);
+
+            for (answer, prefix) in answers.iter_mut().zip(["Part 1: ", "Part 2: "]) {
+                if let Some(rest) = line.strip_prefix(prefix) {
+                    *answer = rest
+                        .strip_prefix(ANSI_BOLD)
+                        .and_then(|rest| rest.split_once(ANSI_RESET))
+                        .map(|(value, _)| value.to_string());
+                }
+            }
+        }
+
+        answers
+    }
+
+    /// Parse the detailed statistics that follow the timing, e.g. `[median 70.1ns, min 69.0ns, stddev 2.0ns]`.
+    fn parse_stats(line: &str, mean: f64) -> Option<PartStats> {
+        let (head, tail) = line.split_once(" samples)")?;
+        let samples = head.rsplit('@').next()?.trim().parse().ok()?;
+        // the details may be wrapped in ANSI escape codes, which contain `[` but never `]`.
+        let details = tail.split_once(']')?.0.rsplit_once('[')?.1;
+
+        let mut stats = PartStats {
+            mean,
+            samples,
+            ..PartStats::default()
+        };
+
+        for detail in details.split(',') {
+            let (key, value) = detail.trim().split_once(' ')?;
+            match key {
+                "median" => stats.median = parse_duration(value)?,
+                "min" => stats.min = parse_duration(value)?,
+                "stddev" => stats.stddev = parse_duration(value)?,
+                "parse" => stats.parse = Some(parse_duration(value)?),
+                "allocs" => stats.allocations = Some(value.parse().ok()?),
+                _ => {}
+            }
+        }
+
+        Some(stats)
+    }
+
     fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
         s.split(postfix).next()?.parse().ok()
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
+    /// Parse a formatted [`std::time::Duration`] (e.g. `74.13µs`) to nanoseconds.
+    pub fn parse_duration(str_timing: &str) -> Option<f64> {
         // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+        match str_timing {
+            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
+            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
+            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
+            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
+        }
+    }
+
+    fn parse_time(line: &str) -> Option<(&str, f64)> {
         let str_timing = line
             .split(" samples)")
             .next()?
             .split('(')
-            .last()?
+            .next_back()?
             .split('@')
             .next()?
             .trim();
 
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
+        let parsed_timing = parse_duration(str_timing)?;
 
         Some((str_timing, parsed_timing))
     }
@@ -205,7 +284,7 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::{parse_answers, parse_exec_time};
 
         use crate::day;
 
@@ -239,6 +318,39 @@ pub mod child_commands {
             assert_eq!(res.part_2.unwrap(), "100ms");
         }
 
+        #[test]
+        fn parses_detailed_stats() {
+            let res = parse_exec_time(
+                &[
+                    "Part 1: 0 (74.13ns @ 100000 samples) \x1b[3m[median 70.0ns, min 69.0ns, stddev 2.0ns, parse 10.0ns, allocs 4]\x1b[0m".into(),
+                    "Part 2: 10 (74.13ms @ 99 samples)".into(),
+                    "".into(),
+                ],
+                day!(1),
+            );
+            let stats = res.part_1_stats.unwrap();
+            assert_approx_eq!(stats.mean, 74.13_f64);
+            assert_approx_eq!(stats.median, 70_f64);
+            assert_approx_eq!(stats.min, 69_f64);
+            assert_approx_eq!(stats.stddev, 2_f64);
+            assert_eq!(stats.samples, 100000);
+            assert_eq!(stats.parse, Some(10_f64));
+            assert_eq!(stats.allocations, Some(4));
+            assert_eq!(res.part_2_stats, None);
+        }
+
+        #[test]
+        fn parses_answers() {
+            let answers = parse_answers(&[
+                "Part 1: \x1b[1m11\x1b[0mPart 1: \x1b[1m11\x1b[0m (63.2µs)".into(),
+                "Part 2: ✖             ".into(),
+            ]);
+            assert_eq!(answers, [Some("11".into()), None]);
+
+            let answers = parse_answers(&["Part 2: ▼ (1.0ms)".into(), "#..#".into()]);
+            assert_eq!(answers, [None, None]);
+        }
+
         #[test]
         fn parses_missing_parts() {
             let res = parse_exec_time(
@@ -1,35 +1,91 @@
 /// Encapsulates code that interacts with solution functions.
+use std::cell::Cell;
+use std::cmp;
 use std::fmt::Display;
 use std::hint::black_box;
-use std::io::{stdout, Write};
-use std::process::Output;
+use std::io::{stdin, stdout, IsTerminal, Write};
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
 
+use crate::template::aoc_client::{format_countdown, Outcome, Reply};
+use crate::template::cli::SolutionArgs;
+use crate::template::clock;
+use crate::template::commands::stars;
+use crate::template::ledger::Ledger;
 use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{alloc, answers, aoc_client, profile, Day, ANSI_ITALIC, ANSI_RESET};
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+pub fn run_part<I: Clone, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    day: Day,
+    part: u8,
+    args: &SolutionArgs,
+) {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, duration, samples, stats) = run_timed(func, input, args.time, |result| {
+        print_result(result, &part_str, "")
+    });
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    print_result(
+        &result,
+        &part_str,
+        &format_duration(&duration, samples, stats.as_ref()),
+    );
 
     if let Some(result) = result {
-        submit_result(result, day, part);
+        let answer = result.to_string();
+        match submit_result(&answer, day, part, args) {
+            Some(Ok(reply)) => handle_reply(&reply, &answer, day, part),
+            Some(Err(e)) => eprintln!("Failed to submit result: {e}"),
+            None => {}
+        }
     }
 }
 
+/// Summary of a benchmark run, printed after the average execution time.
+struct BenchStats {
+    median: Duration,
+    min: Duration,
+    stddev: Duration,
+    /// Average time spent in [`measure_parse`] per execution.
+    parse: Option<Duration>,
+    /// Heap allocations of the first execution.
+    allocations: Option<u64>,
+}
+
+thread_local! {
+    static PARSE_TIME: Cell<(Duration, u32)> = const { Cell::new((Duration::ZERO, 0)) };
+}
+
+/// Run the parsing step of a solution and record its execution time.
+/// When benching, the average parse time is reported alongside the execution time of a part,
+/// e.g. `let grid = measure_parse(|| parse_grid(input));`.
+pub fn measure_parse<T>(parse: impl FnOnce() -> T) -> T {
+    let timer = Instant::now();
+    let parsed = parse();
+    let elapsed = timer.elapsed();
+    PARSE_TIME.with(|time| {
+        let (total, calls) = time.get();
+        time.set((total + elapsed, calls + 1));
+    });
+    parsed
+}
+
+fn take_parse_time() -> (Duration, u32) {
+    PARSE_TIME.with(|time| time.replace((Duration::ZERO, 0)))
+}
+
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
+    timed: bool,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, Duration, u128, Option<BenchStats>) {
+    let allocations_before = alloc::allocations();
     let timer = Instant::now();
     let result = {
         let input = input.clone();
@@ -40,19 +96,24 @@ fn run_timed<I: Clone, T>(
         func(input)
     };
     let base_time = timer.elapsed();
+    let allocations = alloc::allocations().map(|after| after - allocations_before.unwrap_or(0));
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    if timed {
+        let (mean, samples, mut stats) = bench(func, input, &base_time);
+        stats.allocations = allocations;
+        (result, mean, samples, Some(stats))
     } else {
-        (base_time, 1)
-    };
-
-    (result, run.0, run.1)
+        (result, base_time, 1, None)
+    }
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+) -> (Duration, u128, BenchStats) {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
@@ -63,6 +124,8 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
 
     let mut timers: Vec<Duration> = vec![];
 
+    take_parse_time();
+
     for _ in 0..bench_iterations {
         // need a clone here to make the borrow checker happy.
         let cloned = input.clone();
@@ -71,11 +134,23 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
+    let (parse_total, parse_calls) = take_parse_time();
+
+    #[allow(clippy::cast_possible_truncation)]
+    let mean = average_duration(&timers) as u64;
+
+    timers.sort_unstable();
+
+    let stats = BenchStats {
+        median: timers[timers.len() / 2],
+        min: timers[0],
+        stddev: Duration::from_nanos(stddev_duration(&timers, mean)),
         #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+        parse: (parse_calls > 0).then(|| parse_total / bench_iterations as u32),
+        allocations: None,
+    };
+
+    (Duration::from_nanos(mean), bench_iterations, stats)
 }
 
 fn average_duration(numbers: &[Duration]) -> u128 {
@@ -86,12 +161,50 @@ fn average_duration(numbers: &[Duration]) -> u128 {
         / numbers.len() as u128
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn stddev_duration(numbers: &[Duration], mean: u64) -> u64 {
+    let variance = numbers
+        .iter()
+        .map(|x| (x.as_nanos() as f64 - mean as f64).powi(2))
+        .sum::<f64>()
+        / numbers.len() as f64;
+    variance.sqrt() as u64
+}
+
+fn format_duration(duration: &Duration, samples: u128, stats: Option<&BenchStats>) -> String {
     if samples == 1 {
-        format!(" ({duration:.1?})")
-    } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        return format!(" ({duration:.1?})");
     }
+
+    let mut str = format!(" ({duration:.1?} @ {samples} samples)");
+
+    // detailed statistics are parsed by `cargo time`, see `run_multi::child_commands`.
+    if let Some(stats) = stats {
+        let mut details = vec![
+            format!("median {:.1?}", stats.median),
+            format!("min {:.1?}", stats.min),
+            format!("stddev {:.1?}", stats.stddev),
+        ];
+
+        if let Some(parse) = stats.parse {
+            details.push(format!("parse {parse:.1?}"));
+        }
+
+        if let Some(allocations) = stats.allocations {
+            details.push(format!("allocs {allocations}"));
+        }
+
+        str.push_str(&format!(
+            " {ANSI_ITALIC}[{}]{ANSI_RESET}",
+            details.join(", ")
+        ));
+    }
+
+    str
 }
 
 fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
@@ -129,41 +242,120 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
+/// Try to submit one part of the solution if:
+///  1. `--submit` was passed for this part.
+///  2. a session cookie is configured.
+///  3. the submission ledger does not know the answer to be wrong.
+fn submit_result(
+    answer: &str,
     day: Day,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
-
-    if !args.contains(&"--submit".into()) {
+    args: &SolutionArgs,
+) -> Option<Result<Reply, aoc_client::AocClientError>> {
+    if args.submit != Some(part) {
         return None;
     }
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    if let Err(e) = aoc_client::check() {
+        return Some(Err(e));
     }
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
-
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    let mut ledger = match Ledger::read(day) {
+        Ok(ledger) => ledger,
+        Err(e) => {
+            eprintln!("Not submitting `{answer}`: {e} Fix or remove it to submit again.");
+            return None;
+        }
     };
 
-    if part_submit != part {
+    if let Err(refusal) = ledger.check(part, answer, clock::now()) {
+        eprintln!("Not submitting `{answer}`: {refusal}");
         return None;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    if !args.yes && !confirm(&format!("Submit `{answer}` for day {day}, part {part}?")) {
+        eprintln!("Not submitting `{answer}`.");
+        return None;
+    }
+
+    println!("Submitting result...");
+    let response = aoc_client::submit(day, part, answer);
+
+    if let Ok(reply) = &response {
+        ledger.record(part, answer, reply.outcome, clock::now());
+        if let Err(e) = ledger.store(day) {
+            eprintln!("Failed to store submission ledger: {e}");
+        }
+    }
+
+    Some(response)
+}
+
+/// Print the response to a submission. An accepted answer is recorded in `data/answers`,
+/// which updates the stars table, and the puzzle description is downloaded again to reveal part two.
+fn handle_reply(reply: &Reply, answer: &str, day: Day, part: u8) {
+    println!("{}", reply.message);
+
+    match reply.outcome {
+        Outcome::Correct => {
+            let profile = profile::current();
+            if let Err(e) = answers::store(day, part, answer, profile.as_deref()) {
+                eprintln!("Failed to record answer: {e}");
+            }
+
+            // the readme shows the progress of the default profile.
+            if profile.is_none() {
+                stars::handle();
+            }
+
+            match aoc_client::update_puzzle(day) {
+                Ok(()) if part == 1 => println!("Updated puzzle description with part two."),
+                Ok(()) => {}
+                Err(e) => eprintln!("Failed to update puzzle description: {e}"),
+            }
+        }
+        Outcome::Incorrect { wait, .. } | Outcome::RateLimited { wait } => {
+            eprintln!("Submissions are locked for {}.", format_countdown(wait));
+        }
+        Outcome::AlreadySolved => {
+            eprintln!("This part is already solved. Record its answer with `cargo answer {day} {part} <answer>`.");
+        }
+        Outcome::NotLoggedIn => {
+            eprintln!("The session cookie was not accepted, it may have expired.");
+        }
+        Outcome::Unknown => {}
+    }
+}
+
+/// Ask a yes/no question on the terminal. Answers no if stdin is not a terminal.
+fn confirm(question: &str) -> bool {
+    if !stdin().is_terminal() {
+        eprintln!("{question} Pass --yes to submit without confirmation.");
+        return false;
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    print!("{question} [y/N] ");
+    let _ = stdout().flush();
+
+    let mut line = String::new();
+    stdin().read_line(&mut line).is_ok() && matches!(line.trim(), "y" | "Y" | "yes")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{measure_parse, take_parse_time};
+
+    #[test]
+    fn measures_parse_time() {
+        let numbers: Vec<u32> =
+            measure_parse(|| "1 2 3".split(' ').map(|x| x.parse().unwrap()).collect());
+        measure_parse(|| ());
+
+        assert_eq!(numbers, vec![1, 2, 3]);
+        assert_eq!(take_parse_time().1, 2);
+        // taking the time resets it for the next benchmark.
+        assert_eq!(take_parse_time().1, 0);
+    }
 }
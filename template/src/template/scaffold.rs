@@ -0,0 +1,312 @@
+/// Module that creates the files of a day as one transaction.
+/// Existing files are kept unless they may be replaced, and if a file cannot be written,
+/// everything written before is undone, so a failed scaffold leaves the project as it was.
+use std::{
+    error::Error,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use crate::template::flavors::FlavorError;
+use crate::template::puzzle::Year;
+
+/// What happens to a file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    Create,
+    Overwrite,
+    /// The file exists and is left untouched.
+    Keep,
+}
+
+/// A file of the day, with the action decided when it was planned.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Step {
+    pub path: PathBuf,
+    pub action: Action,
+    contents: String,
+}
+
+/// The files to write, in order.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Plan {
+    pub steps: Vec<Step>,
+}
+
+/// A change that was written, and how to undo it.
+#[derive(Debug)]
+enum Done {
+    CreatedDir(PathBuf),
+    Created(PathBuf),
+    Overwrote(PathBuf, Vec<u8>),
+}
+
+/// The changes of an applied plan, to undo them if a later step fails, e.g. the download of the input.
+#[derive(Debug)]
+#[must_use]
+pub struct Applied {
+    done: Vec<Done>,
+}
+
+impl Applied {
+    /// Undo the changes. Returns the paths that could not be restored.
+    pub fn rollback(self) -> Vec<PathBuf> {
+        rollback(self.done)
+    }
+}
+
+impl Plan {
+    /// Add a file. An existing file is only overwritten if `replace` is set.
+    pub fn add(&mut self, path: impl Into<PathBuf>, contents: impl Into<String>, replace: bool) {
+        let path = path.into();
+        let action = match (path.exists(), replace) {
+            (false, _) => Action::Create,
+            (true, true) => Action::Overwrite,
+            (true, false) => Action::Keep,
+        };
+        self.steps.push(Step {
+            path,
+            action,
+            contents: contents.into(),
+        });
+    }
+
+    /// Write the planned files. If one fails, the changes made before are undone.
+    pub fn apply(&self) -> Result<Applied, ScaffoldError> {
+        let mut done = vec![];
+
+        for step in &self.steps {
+            if let Err(e) = write(step, &mut done) {
+                let not_restored = rollback(done);
+                return Err(ScaffoldError::Write {
+                    path: step.path.clone(),
+                    error: e.to_string(),
+                    not_restored,
+                });
+            }
+        }
+
+        Ok(Applied { done })
+    }
+}
+
+fn write(step: &Step, done: &mut Vec<Done>) -> std::io::Result<()> {
+    match step.action {
+        Action::Keep => Ok(()),
+        Action::Create => {
+            if let Some(parent) = step.path.parent() {
+                create_dirs(parent, done)?;
+            }
+            // fails instead of truncating if the file was created since it was planned.
+            let mut file = OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&step.path)?;
+            done.push(Done::Created(step.path.clone()));
+            file.write_all(step.contents.as_bytes())
+        }
+        Action::Overwrite => {
+            let previous = fs::read(&step.path)?;
+            done.push(Done::Overwrote(step.path.clone(), previous));
+            fs::write(&step.path, &step.contents)
+        }
+    }
+}
+
+/// Create a directory and its missing parents, recording each one that did not exist.
+fn create_dirs(dir: &Path, done: &mut Vec<Done>) -> std::io::Result<()> {
+    let mut missing: Vec<&Path> = dir
+        .ancestors()
+        .take_while(|dir| !dir.as_os_str().is_empty() && !dir.exists())
+        .collect();
+    missing.reverse();
+
+    for dir in missing {
+        fs::create_dir(dir)?;
+        done.push(Done::CreatedDir(dir.to_path_buf()));
+    }
+    Ok(())
+}
+
+/// Undo the changes in reverse order. Returns the paths that could not be restored.
+fn rollback(done: Vec<Done>) -> Vec<PathBuf> {
+    done.into_iter()
+        .rev()
+        .filter_map(|change| {
+            let (path, result) = match change {
+                Done::CreatedDir(path) => {
+                    let result = fs::remove_dir(&path);
+                    (path, result)
+                }
+                Done::Created(path) => {
+                    let result = fs::remove_file(&path);
+                    (path, result)
+                }
+                Done::Overwrote(path, previous) => {
+                    let result = fs::write(&path, previous);
+                    (path, result)
+                }
+            };
+            result.is_err().then_some(path)
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ScaffoldError {
+    /// Solutions are shared by every year, so only the year the project is configured for can be scaffolded.
+    OtherYear(Year),
+    Flavor(FlavorError),
+    /// A file could not be written, with the paths the rollback could not restore.
+    Write {
+        path: PathBuf,
+        error: String,
+        not_restored: Vec<PathBuf>,
+    },
+    /// The puzzle could not be downloaded after scaffolding, with the paths the rollback could not restore.
+    Download {
+        error: String,
+        not_restored: Vec<PathBuf>,
+    },
+}
+
+impl Error for ScaffoldError {}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaffoldError::OtherYear(year) => write!(
+                f,
+                "`scaffold` creates solutions for the year the project is configured for, not {year}. Set AOC_YEAR in `.cargo/config.toml` of a separate project to solve {year}."
+            ),
+            ScaffoldError::Flavor(e) => write!(f, "{e}"),
+            ScaffoldError::Write {
+                path,
+                error,
+                not_restored,
+            } => {
+                write!(f, "failed to write \"{}\": {error}.", path.display())?;
+                write_rollback(f, not_restored)
+            }
+            ScaffoldError::Download {
+                error,
+                not_restored,
+            } => {
+                write!(f, "{error}")?;
+                if not_restored.is_empty() {
+                    write!(f, " The scaffolded files were removed.")
+                } else {
+                    write_rollback(f, not_restored)
+                }
+            }
+        }
+    }
+}
+
+fn write_rollback(f: &mut std::fmt::Formatter<'_>, not_restored: &[PathBuf]) -> std::fmt::Result {
+    if not_restored.is_empty() {
+        write!(f, " No files were changed.")
+    } else {
+        let paths: Vec<_> = not_restored
+            .iter()
+            .map(|path| format!("\"{}\"", path.display()))
+            .collect();
+        write!(f, " These could not be restored: {}.", paths.join(", "))
+    }
+}
+
+impl From<FlavorError> for ScaffoldError {
+    fn from(e: FlavorError) -> Self {
+        ScaffoldError::Flavor(e)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs, path::PathBuf};
+
+    use super::{Action, Plan, ScaffoldError};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-scaffold-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn keeps_existing_files() {
+        let dir = temp_dir("keep");
+        fs::write(dir.join("01.rs"), "solution").unwrap();
+        fs::write(dir.join("input.txt"), "1 2 3").unwrap();
+
+        let mut plan = Plan::default();
+        plan.add(dir.join("01.rs"), "template", true);
+        plan.add(dir.join("input.txt"), "", false);
+        plan.add(dir.join("examples/01.txt"), "", false);
+        let actions: Vec<_> = plan.steps.iter().map(|step| step.action).collect();
+        let result = plan.apply();
+
+        let module = fs::read_to_string(dir.join("01.rs")).unwrap();
+        let input = fs::read_to_string(dir.join("input.txt")).unwrap();
+        let example = fs::read_to_string(dir.join("examples/01.txt"));
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(actions, [Action::Overwrite, Action::Keep, Action::Create]);
+        assert!(result.is_ok());
+        assert_eq!(module, "template");
+        assert_eq!(input, "1 2 3");
+        assert_eq!(example.unwrap(), "");
+    }
+
+    #[test]
+    fn rolls_back_on_failure() {
+        let dir = temp_dir("rollback");
+        fs::write(dir.join("01.rs"), "solution").unwrap();
+        // a file where a directory is expected cannot be written below.
+        fs::write(dir.join("inputs"), "").unwrap();
+
+        let mut plan = Plan::default();
+        plan.add(dir.join("01.rs"), "template", true);
+        plan.add(dir.join("examples/nested/01.txt"), "", false);
+        plan.add(dir.join("inputs/01.txt"), "", false);
+        let result = plan.apply();
+
+        let module = fs::read_to_string(dir.join("01.rs")).unwrap();
+        let examples = dir.join("examples").exists();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(matches!(
+            result,
+            Err(ScaffoldError::Write { path, not_restored, .. })
+                if path == dir.join("inputs/01.txt") && not_restored.is_empty()
+        ));
+        assert_eq!(module, "solution");
+        assert!(!examples);
+    }
+
+    #[test]
+    fn undoes_applied_plan() {
+        let dir = temp_dir("undo");
+        fs::write(dir.join("01.rs"), "solution").unwrap();
+
+        let mut plan = Plan::default();
+        plan.add(dir.join("01.rs"), "template", true);
+        plan.add(dir.join("inputs/01.txt"), "", false);
+        let not_restored = plan.apply().unwrap().rollback();
+
+        let module = fs::read_to_string(dir.join("01.rs")).unwrap();
+        let inputs = dir.join("inputs").exists();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(not_restored.is_empty());
+        assert_eq!(module, "solution");
+        assert!(!inputs);
+    }
+}
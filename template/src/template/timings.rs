@@ -1,9 +1,12 @@
 use std::{collections::HashMap, fs, io::Error, str::FromStr};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::{puzzle, Day};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+/// Timings of the active year, e.g. `data/timings.json` or `data/2023/timings.json`.
+fn get_timings_path() -> String {
+    format!("./{}/timings.json", puzzle::data_dir())
+}
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
@@ -12,6 +15,46 @@ pub struct Timing {
     pub part_1: Option<String>,
     pub part_2: Option<String>,
     pub total_nanos: f64,
+    pub part_1_stats: Option<PartStats>,
+    pub part_2_stats: Option<PartStats>,
+}
+
+/// Represents detailed benchmark statistics for a single part.
+/// All durations are stored in nanoseconds.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PartStats {
+    pub mean: f64,
+    pub median: f64,
+    pub min: f64,
+    pub stddev: f64,
+    pub samples: u64,
+    pub parse: Option<f64>,
+    pub allocations: Option<u64>,
+    /// Mean of the previously stored run, used to show a trend.
+    pub previous: Option<f64>,
+}
+
+impl Timing {
+    pub fn stats(&self, part: u8) -> Option<&PartStats> {
+        match part {
+            1 => self.part_1_stats.as_ref(),
+            2 => self.part_2_stats.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Remember the means of an older timing of the same day as previous values.
+    fn with_previous(mut self, older: &Timing) -> Self {
+        for (stats, old) in [
+            (&mut self.part_1_stats, &older.part_1_stats),
+            (&mut self.part_2_stats, &older.part_2_stats),
+        ] {
+            if let (Some(stats), Some(old)) = (stats, old) {
+                stats.previous = Some(old.mean);
+            }
+        }
+        self
+    }
 }
 
 /// Represents benchmark times for a set of days.
@@ -25,13 +68,17 @@ impl Timings {
     /// Dehydrate timings to a JSON file.
     pub fn store_file(&self) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let path = get_timings_path();
+        if let Some(dir) = std::path::Path::new(&path).parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = fs::File::create(path)?;
         json.format_to(&mut file)
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
     pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
+        fs::read_to_string(get_timings_path())
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from)
             .unwrap_or_default()
@@ -42,7 +89,10 @@ impl Timings {
         let mut data: Vec<Timing> = vec![];
 
         for timing in &new.data {
-            data.push(timing.clone());
+            match self.data.iter().find(|t| t.day == timing.day) {
+                Some(old) => data.push(timing.clone().with_previous(old)),
+                None => data.push(timing.clone()),
+            }
         }
 
         for timing in &self.data {
@@ -51,7 +101,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
@@ -67,6 +117,29 @@ impl Timings {
     }
 }
 
+/// Format nanoseconds like a [`std::time::Duration`], e.g. `74.1µs`.
+pub fn format_nanos(nanos: f64) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let duration = std::time::Duration::from_nanos(nanos as u64);
+    format!("{duration:.1?}")
+}
+
+/// Median of samples, the mean of the two middle ones for an even number of samples.
+pub fn median(values: &mut [f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+
+    values.sort_unstable_by(f64::total_cmp);
+    let mid = values.len() / 2;
+
+    if values.len().is_multiple_of(2) {
+        Some((values[mid - 1] + values[mid]) / 2.0)
+    } else {
+        Some(values[mid])
+    }
+}
+
 /* -------------------------------------------------------------------------- */
 
 impl From<Timings> for JsonValue {
@@ -133,6 +206,15 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        for (key, stats) in [
+            ("part_1_stats", &value.part_1_stats),
+            ("part_2_stats", &value.part_2_stats),
+        ] {
+            if let Some(stats) = stats {
+                map.insert(key.into(), JsonValue::from(stats));
+            }
+        }
+
         JsonValue::Object(map)
     }
 }
@@ -148,7 +230,8 @@ impl TryFrom<&JsonValue> for Timing {
         let day = json
             .get("day")
             .and_then(|v| v.get::<String>())
-            .and_then(|day| Day::from_str(day).ok())
+            .and_then(|day| day.parse().ok())
+            .and_then(Day::new)
             .ok_or("Expected timing.day to be a Day struct.")?;
 
         let part_1 = json
@@ -166,11 +249,80 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // detailed statistics are optional for compatibility with older timing files.
+        let part_1_stats = json
+            .get("part_1_stats")
+            .map(PartStats::try_from)
+            .transpose()?;
+
+        let part_2_stats = json
+            .get("part_2_stats")
+            .map(PartStats::try_from)
+            .transpose()?;
+
         Ok(Timing {
             day,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
             total_nanos,
+            part_1_stats,
+            part_2_stats,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartStats> for JsonValue {
+    fn from(value: &PartStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("mean".into(), JsonValue::Number(value.mean));
+        map.insert("median".into(), JsonValue::Number(value.median));
+        map.insert("min".into(), JsonValue::Number(value.min));
+        map.insert("stddev".into(), JsonValue::Number(value.stddev));
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+
+        #[allow(clippy::cast_precision_loss)]
+        for (key, value) in [
+            ("parse", value.parse),
+            ("allocations", value.allocations.map(|x| x as f64)),
+            ("previous", value.previous),
+        ] {
+            if let Some(value) = value {
+                map.insert(key.into(), JsonValue::Number(value));
+            }
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing stats to be a JSON object.")?;
+
+        let number = |key: &str| json.get(key).and_then(|v| v.get::<f64>().copied());
+
+        let required =
+            |key: &str| number(key).ok_or(format!("Expected timing stats.{key} to be a number."));
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(PartStats {
+            mean: required("mean")?,
+            median: required("median")?,
+            min: required("min")?,
+            stddev: required("stddev")?,
+            samples: required("samples")? as u64,
+            parse: number("parse"),
+            allocations: number("allocations").map(|x| x as u64),
+            previous: number("previous"),
         })
     }
 }
@@ -181,7 +333,7 @@ impl TryFrom<&JsonValue> for Timing {
 mod tests {
     use crate::day;
 
-    use super::{Timing, Timings};
+    use super::{median, Timing, Timings};
 
     fn get_mock_timings() -> Timings {
         Timings {
@@ -191,18 +343,24 @@ mod tests {
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    part_1_stats: None,
+                    part_2_stats: None,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    part_1_stats: None,
+                    part_2_stats: None,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: None,
                     total_nanos: 4e+10,
+                    part_1_stats: None,
+                    part_2_stats: None,
                 },
             ],
         }
@@ -282,10 +440,12 @@ mod tests {
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
                     total_nanos: 3_000_000_000_f64,
+                    part_1_stats: None,
+                    part_2_stats: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -296,10 +456,12 @@ mod tests {
                     part_1: Some("1ms".into()),
                     part_2: None,
                     total_nanos: 1_000_000_000_f64,
+                    part_1_stats: None,
+                    part_2_stats: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
@@ -310,10 +472,12 @@ mod tests {
                     part_1: None,
                     part_2: None,
                     total_nanos: 0.0,
+                    part_1_stats: None,
+                    part_2_stats: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
@@ -334,6 +498,8 @@ mod tests {
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    part_1_stats: None,
+                    part_2_stats: None,
                 }],
             };
             let merged = timings.merge(&other);
@@ -354,6 +520,8 @@ mod tests {
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    part_1_stats: None,
+                    part_2_stats: None,
                 }],
             };
             let merged = timings.merge(&other);
@@ -381,4 +549,23 @@ mod tests {
             assert_eq!(merged.data.len(), 3);
         }
     }
+
+    mod median {
+        use super::median;
+
+        #[test]
+        fn median_of_odd_samples() {
+            assert_eq!(median(&mut [3.0, 1.0, 2.0]), Some(2.0));
+        }
+
+        #[test]
+        fn median_of_even_samples() {
+            assert_eq!(median(&mut [4.0, 1.0, 3.0, 2.0]), Some(2.5));
+        }
+
+        #[test]
+        fn median_of_no_samples() {
+            assert_eq!(median(&mut []), None);
+        }
+    }
 }
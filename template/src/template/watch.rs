@@ -0,0 +1,204 @@
+/// Module that reruns a command whenever the files of a day change.
+/// Files are polled for changes of their modification time, so no platform specific file events are needed.
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{stdout, IsTerminal, Write},
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+use crate::template::examples::get_example_paths;
+use crate::template::run_multi::get_path_for_bin;
+use crate::template::{profile, Day, ANSI_BOLD, ANSI_GREEN, ANSI_RED, ANSI_RESET};
+
+/// Interval in which files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Time without further changes to wait for before rerunning, so saving several files reruns once.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Modification times of the watched files. Missing files are recorded as `None`, so creating one counts as a change.
+type Snapshot = BTreeMap<PathBuf, Option<SystemTime>>;
+
+/// Files a day depends on: its solution, its example files, optionally its input, and the shared library.
+pub fn day_files(day: Day, with_input: bool) -> Vec<PathBuf> {
+    let mut files = vec![PathBuf::from(get_path_for_bin(day))];
+    files.extend(
+        get_example_paths(day, 1)
+            .into_iter()
+            .chain(get_example_paths(day, 2))
+            .map(PathBuf::from),
+    );
+    if with_input {
+        let profile = profile::current();
+        files.push(profile::data_path("inputs", &format!("{day}.txt"), profile.as_deref()).into());
+    }
+    files.extend(library_files(Path::new("src")));
+    files
+}
+
+/// Rust files below `dir`, without the solutions in `src/bin`.
+fn library_files(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    let mut files = vec![];
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            if entry.file_name() != "bin" {
+                files.extend(library_files(&path));
+            }
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+    files.sort();
+    files
+}
+
+fn snapshot(files: &[PathBuf]) -> Snapshot {
+    files
+        .iter()
+        .map(|path| {
+            let modified = fs::metadata(path).and_then(|m| m.modified()).ok();
+            (path.clone(), modified)
+        })
+        .collect()
+}
+
+/// Files that were created, changed or removed between two snapshots of the same files.
+fn changes(before: &Snapshot, after: &Snapshot) -> Vec<PathBuf> {
+    after
+        .iter()
+        .filter(|(path, modified)| before.get(*path) != Some(modified))
+        .map(|(path, _)| path.clone())
+        .collect()
+}
+
+/// Outcome of a run, shown after its output until the next run.
+struct Run {
+    success: bool,
+    elapsed: Duration,
+}
+
+/// Run `run` now and again whenever one of `files` changes, until the process is stopped.
+/// `files` is called again before every run, so new files of the shared library are picked up.
+/// `run` reports whether the command succeeded.
+pub fn watch(label: &str, files: impl Fn() -> Vec<PathBuf>, mut run: impl FnMut() -> bool) -> ! {
+    let mut changed: Vec<PathBuf> = vec![];
+    let mut last: Option<Run> = None;
+
+    loop {
+        clear_screen();
+        if let Some(last) = &last {
+            let names: Vec<_> = changed
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect();
+            println!(
+                "🔁 Changed: {}. Last run: {}",
+                names.join(", "),
+                format_run(last)
+            );
+        }
+
+        let files = files();
+        let mut before = snapshot(&files);
+
+        let timer = Instant::now();
+        let success = run();
+        let run = Run {
+            success,
+            elapsed: timer.elapsed(),
+        };
+
+        println!("---");
+        println!(
+            "{label}: {} 👀 Watching {} files, press Ctrl-C to stop.",
+            format_run(&run),
+            files.len()
+        );
+        last = Some(run);
+
+        // compared to the files before the run, so changes saved while it ran trigger another one.
+        changed = wait_for_changes(&files, &mut before);
+    }
+}
+
+/// Block until watched files change and no further changes follow for [`DEBOUNCE`].
+fn wait_for_changes(files: &[PathBuf], current: &mut Snapshot) -> Vec<PathBuf> {
+    let mut changed: Vec<PathBuf> = vec![];
+    let mut last_change: Option<Instant> = None;
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        let next = snapshot(files);
+        let new_changes = changes(current, &next);
+        *current = next;
+
+        if !new_changes.is_empty() {
+            last_change = Some(Instant::now());
+            for path in new_changes {
+                if !changed.contains(&path) {
+                    changed.push(path);
+                }
+            }
+        } else if last_change.is_some_and(|at| at.elapsed() >= DEBOUNCE) {
+            return changed;
+        }
+    }
+}
+
+fn format_run(run: &Run) -> String {
+    let (color, result) = if run.success {
+        (ANSI_GREEN, "✔ passed")
+    } else {
+        (ANSI_RED, "✖ failed")
+    };
+    format!(
+        "{ANSI_BOLD}{color}{result}{ANSI_RESET} in {:.2}s.",
+        run.elapsed.as_secs_f64()
+    )
+}
+
+fn clear_screen() {
+    let mut stdout = stdout();
+    if stdout.is_terminal() {
+        print!("\x1b[2J\x1b[H");
+        let _ = stdout.flush();
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs, path::PathBuf};
+
+    use super::{changes, library_files, snapshot};
+
+    #[test]
+    fn detects_changes() {
+        let dir = env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(dir.join("src/bin")).unwrap();
+        fs::write(dir.join("src/lib.rs"), "").unwrap();
+        fs::write(dir.join("src/bin/01.rs"), "").unwrap();
+        let files: Vec<PathBuf> = vec![dir.join("src/lib.rs"), dir.join("examples.txt")];
+
+        let before = snapshot(&files);
+        assert!(changes(&before, &snapshot(&files)).is_empty());
+
+        fs::write(dir.join("examples.txt"), "1 2 3").unwrap();
+        let after = snapshot(&files);
+        let library = library_files(&dir.join("src"));
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(changes(&before, &after), vec![dir.join("examples.txt")]);
+        assert_eq!(library, vec![dir.join("src/lib.rs")]);
+    }
+}